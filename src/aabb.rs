@@ -0,0 +1,143 @@
+use crate::Vector;
+
+/// An axis-aligned box of integer points with `N` axes, from `min` to `max` inclusive.
+/// It is empty when `min` is past `max` on any axis.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Aabb<const N: usize> {
+    pub min: Vector<N>,
+    pub max: Vector<N>,
+}
+
+pub type Rect = Aabb<2>;
+pub type Cuboid = Aabb<3>;
+
+impl<const N: usize> Aabb<N> {
+    pub fn new(min: Vector<N>, max: Vector<N>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.min[axis] > self.max[axis])
+    }
+
+    /// The number of points inside.
+    pub fn volume(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        (0..N)
+            .map(|axis| self.max[axis].abs_diff(self.min[axis]) + 1)
+            .product()
+    }
+
+    pub fn contains(&self, point: &Vector<N>) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    /// The points inside both boxes, or `None` when they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Self::new(
+            Vector::max(&self.min, &other.min),
+            Vector::min(&self.max, &other.max),
+        );
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The points inside `self` but not `other`, as disjoint boxes. There are at most
+    /// two per axis.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        };
+        // Slices off what lies before and after the overlap along each axis in turn,
+        // leaving only the overlap
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            if rest.min[axis] < overlap.min[axis] {
+                let mut before = rest;
+                before.max[axis] = overlap.min[axis] - 1;
+                pieces.push(before);
+                rest.min[axis] = overlap.min[axis];
+            }
+            if rest.max[axis] > overlap.max[axis] {
+                let mut after = rest;
+                after.min[axis] = overlap.max[axis] + 1;
+                pieces.push(after);
+                rest.max[axis] = overlap.max[axis];
+            }
+        }
+        pieces
+    }
+
+    /// The number of points inside any of the boxes.
+    pub fn union_volume(boxes: &[Self]) -> usize {
+        let mut disjoint: Vec<Self> = Vec::new();
+        for aabb in boxes {
+            let mut pieces = vec![*aabb];
+            for other in &disjoint {
+                pieces = pieces
+                    .iter()
+                    .flat_map(|piece| piece.difference(other))
+                    .collect();
+            }
+            disjoint.extend(pieces);
+        }
+        disjoint.iter().map(Self::volume).sum()
+    }
+
+    /// Every point inside, with the first axis changing fastest.
+    pub fn points(&self) -> impl Iterator<Item = Vector<N>> + '_ {
+        let first = (!self.is_empty()).then_some(self.min);
+        std::iter::successors(first, move |point| {
+            let mut next = *point;
+            for axis in 0..N {
+                if next[axis] < self.max[axis] {
+                    next[axis] += 1;
+                    return Some(next);
+                }
+                next[axis] = self.min[axis];
+            }
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3};
+
+    #[test]
+    fn algebra() {
+        let a = Cuboid::new(Vec3::new(0, 0, 0), Vec3::new(3, 3, 3));
+        let b = Cuboid::new(Vec3::new(2, 1, -5), Vec3::new(9, 2, 5));
+        assert_eq!(a.volume(), 64);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new(Vec3::new(2, 1, 0), Vec3::new(3, 2, 3)))
+        );
+        assert!(a.contains(&Vec3::new(3, 0, 2)) && !a.contains(&Vec3::new(4, 0, 2)));
+
+        let pieces = a.difference(&b);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<usize>(), 64 - 16);
+        for (i, piece) in pieces.iter().enumerate() {
+            assert!(!piece.intersects(&b));
+            assert!(pieces[i + 1..].iter().all(|other| !piece.intersects(other)));
+        }
+        assert_eq!(Cuboid::union_volume(&[a, b, a]), 64 + 8 * 2 * 11 - 16);
+
+        let rect = Rect::new(Vec2::new(1, 5), Vec2::new(2, 6));
+        let points: Vec<_> = rect.points().collect();
+        assert_eq!(
+            points,
+            [(1, 5), (2, 5), (1, 6), (2, 6)].map(|(x, y)| Vec2::new(x, y))
+        );
+        let empty = Rect::new(Vec2::new(1, 1), Vec2::new(0, 1));
+        assert_eq!((empty.volume(), empty.points().count()), (0, 0));
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+/// What a part produces. Most parts compute a number, a few produce text (which may
+/// span several lines), and some have nothing to compute at all.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    None,
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Str(s) if s.contains('\n'))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(n) => f.pad(&n.to_string()),
+            Self::Str(s) => f.pad(s),
+            Self::None => f.pad("-"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Int(n.into())
+            }
+        })*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Int(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Int(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Str(s.to_string())
+    }
+}
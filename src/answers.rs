@@ -0,0 +1,139 @@
+use crate::solution::Part;
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Known-good answers, read from a small TOML file:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1121
+/// part2 = "some string"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswers(HashMap<(u8, Part), String>);
+
+impl ExpectedAnswers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let input =
+            std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+        input
+            .parse()
+            .with_context(|| format!("Failed to parse {:?}", path))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl FromStr for ExpectedAnswers {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let n = section
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|n| n.parse().ok())
+                    .ok_or(anyhow!("Line {}: expected a [dayNN] section", i + 1))?;
+                day = Some(n);
+                continue;
+            }
+
+            let day = day.ok_or(anyhow!(
+                "Line {}: answer outside of a [dayNN] section",
+                i + 1
+            ))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(anyhow!("Line {}: expected `partN = answer`", i + 1))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(anyhow!("Line {}: unknown key {}", i + 1, key)),
+            };
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            answers.insert((day, part), value.replace("\\n", "\n"));
+        }
+
+        Ok(Self(answers))
+    }
+}
+
+/// Drops a `#` comment, unless the `#` is inside a quoted answer.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = "# Checked-in inputs
+[day01]
+part1 = 1121
+part2 = \"ABC\" # trailing comment
+
+[day25]
+part1 = 471
+"
+        .parse::<ExpectedAnswers>()
+        .unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("1121"));
+        assert_eq!(answers.get(1, Part::Two), Some("ABC"));
+        assert_eq!(answers.get(25, Part::One), Some("471"));
+        assert_eq!(answers.get(25, Part::Two), None);
+        assert_eq!(answers.check(1, Part::One, "1121"), Verdict::Pass);
+        assert_eq!(answers.check(25, Part::Two, "1"), Verdict::Missing);
+    }
+
+    #[test]
+    fn quoted_hash() {
+        let answers = "[day13]\npart2 = \"#.#\\n###\" # comment"
+            .parse::<ExpectedAnswers>()
+            .unwrap();
+
+        assert_eq!(answers.get(13, Part::Two), Some("#.#\n###"));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::answer::Answer;
+use crate::report::{Record, ToRecord, Value};
+use crate::runner::catch_panic;
+use crate::solution::{DynSolution, Part};
+use anyhow::{anyhow, Context, Result};
+use std::time::{Duration, Instant};
+
+/// Summary of repeated timings of the same thing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            p95,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartBench {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: Part,
+    pub answer: Answer,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl ToRecord for PartBench {
+    fn to_record(&self) -> Record {
+        vec![
+            ("day", Value::Int(self.day.into())),
+            ("title", Value::Str(self.title.to_string())),
+            ("part", Value::Int(self.part.number().into())),
+            ("answer", (&self.answer).into()),
+            ("iterations", Value::Int(self.iterations as i128)),
+            ("parse_min_ns", self.parse.min.into()),
+            ("parse_median_ns", self.parse.median.into()),
+            ("parse_mean_ns", self.parse.mean.into()),
+            ("parse_p95_ns", self.parse.p95.into()),
+            ("solve_min_ns", self.solve.min.into()),
+            ("solve_median_ns", self.solve.median.into()),
+            ("solve_mean_ns", self.solve.mean.into()),
+            ("solve_p95_ns", self.solve.p95.into()),
+            ("status", Value::Str("ok".to_string())),
+        ]
+    }
+}
+
+/// Runs `warmup` untimed iterations, then times parsing and solving separately
+/// for `iterations` more. Copying the input for each iteration is not timed. A part
+/// that panics is reported as an error, like one that fails.
+pub fn bench_part(
+    solution: &dyn DynSolution,
+    part: Part,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<PartBench> {
+    let metadata = solution.metadata();
+    catch_panic(|| time_part(solution, part, input, warmup, iterations))
+        .unwrap_or_else(|failure| Err(anyhow!("{}", failure)))
+        .with_context(|| format!("Day {} part {} failed", metadata.day, part))
+}
+
+fn time_part(
+    solution: &dyn DynSolution,
+    part: Part,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<PartBench> {
+    let metadata = solution.metadata();
+    let iterations = iterations.max(1);
+    let mut answer = Answer::None;
+
+    for _ in 0..warmup {
+        answer = solution
+            .parse(input.to_string())
+            .and_then(|parsed| solution.solve(part, parsed))?;
+    }
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let input = input.to_string();
+        let now = Instant::now();
+        let parsed = solution.parse(input)?;
+        parse_samples.push(now.elapsed());
+        let now = Instant::now();
+        answer = solution.solve(part, parsed)?;
+        solve_samples.push(now.elapsed());
+    }
+
+    Ok(PartBench {
+        day: metadata.day,
+        title: metadata.title,
+        part,
+        answer,
+        iterations,
+        parse: Stats::from_samples(&parse_samples).unwrap(),
+        solve: Stats::from_samples(&solve_samples).unwrap(),
+    })
+}
+
+pub fn print_bench_table(benches: &[PartBench]) {
+    let row = |columns: String| println!("{}", columns.trim_end());
+    row(format!(
+        "{:>3}  {:<24}  {:>4}  {:>5}  {:>11}  {:>11}  {:>11}  {:>11}  {:>11}  {:>11}  {:>11}  {:>11}",
+        "Day",
+        "Title",
+        "Part",
+        "Runs",
+        "Parse min",
+        "Parse med",
+        "Parse mean",
+        "Parse p95",
+        "Solve min",
+        "Solve med",
+        "Solve mean",
+        "Solve p95",
+    ));
+    for bench in benches {
+        let stats = |s: &Stats| {
+            [s.min, s.median, s.mean, s.p95]
+                .iter()
+                .map(|d| format!("{:>11}", format!("{:?}", d)))
+                .collect::<Vec<_>>()
+                .join("  ")
+        };
+        row(format!(
+            "{:>3}  {:<24}  {:>4}  {:>5}  {}  {}",
+            bench.day,
+            bench.title,
+            bench.part,
+            bench.iterations,
+            stats(&bench.parse),
+            stats(&bench.solve),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<_> = [5, 1, 4, 2, 3, 100]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(3500));
+        assert_eq!(stats.mean, Duration::from_nanos(19_166_666));
+        assert_eq!(stats.p95, Duration::from_millis(100));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = ();
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Broken";
+
+        fn parse(_: String) -> Result<()> {
+            Ok(())
+        }
+        fn part1(_: ()) -> Result<Answer> {
+            todo!()
+        }
+        fn part2(_: ()) -> Result<Answer> {
+            Ok(Answer::Int(2))
+        }
+    }
+
+    #[test]
+    fn panic() {
+        let error = bench_part(&Broken, Part::One, "", 1, 3).unwrap_err();
+        assert_eq!(error.to_string(), "Day 2 part 1 failed");
+        assert!(format!("{:#}", error).contains("not yet implemented"));
+
+        let bench = bench_part(&Broken, Part::Two, "", 1, 3).unwrap();
+        assert_eq!(bench.answer, Answer::Int(2));
+        assert_eq!(bench.iterations, 3);
+    }
+}
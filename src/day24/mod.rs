@@ -1,9 +0,0 @@
-// Solved using pen and paper <3
-
-pub fn part1(_: String) -> usize {
-    99999795919456
-}
-
-pub fn part2(_: String) -> usize {
-    45311191516111
-}
@@ -0,0 +1,60 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{Context, Result};
+
+pub fn part1(parsed_input: Vec<i32>) -> usize {
+  return parsed_input
+    .windows(2)
+    .filter(|window| window[1] > window[0])
+    .count();
+}
+
+pub fn part2(parsed_input: Vec<i32>) -> usize {
+  return parsed_input
+    .windows(3)
+    .map(|window| window.iter().sum::<i32>())
+    .collect::<Vec<i32>>()
+    .windows(2)
+    .filter(|window| window[1] > window[0])
+    .count();
+}
+
+pub fn parse_input(input: String) -> Result<Vec<i32>> {
+  return input::lines(&input)
+    .map(|n| n.parse().with_context(|| format!("Not a number: {:?}", n)))
+    .collect();
+}
+
+/// `size` depths, drifting deeper with the odd step back up.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  let mut depth = rng.range(100..=200);
+  let mut depths = Vec::with_capacity(size);
+  for _ in 0..size {
+    depths.push(depth.to_string());
+    depth = (depth + rng.range(-10..=20)).max(0);
+  }
+  return depths.join("\n");
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+  type Input = Vec<i32>;
+  const DAY: u8 = 1;
+  const TITLE: &'static str = "Sonar Sweep";
+
+  fn parse(input: String) -> Result<Vec<i32>> {
+    parse_input(input)
+  }
+  fn part1(depths: Vec<i32>) -> Result<Answer> {
+    Ok(part1(depths).into())
+  }
+  fn part2(depths: Vec<i32>) -> Result<Answer> {
+    Ok(part2(depths).into())
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(2000)))
+  }
+}
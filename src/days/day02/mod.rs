@@ -1,6 +1,11 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use std::str::FromStr;
 
-enum Instruction {
+pub enum Instruction {
     Forwards(isize),
     Up(isize),
     Down(isize),
@@ -13,16 +18,16 @@ struct Coordinates {
     aim: isize,
 }
 
-fn parse_input(input: String) -> Vec<Instruction> {
-    return input
-        .split_terminator('\n')
-        .map(Instruction::from_str)
-        .collect::<Result<Vec<_>, _>>() // Cast vector of results to results of vector, like Promise.all()
-        .expect("Parsing failed!");
+pub fn parse_input(input: String) -> Result<Vec<Instruction>> {
+    return input::lines(&input)
+        .map(|line| {
+            Instruction::from_str(line).map_err(|_| anyhow!("Invalid instruction {:?}", line))
+        })
+        .collect::<Result<Vec<_>>>(); // Cast vector of results to results of vector, like Promise.all()
 }
 
 #[derive(Debug, Clone)]
-struct InstructionParseError;
+pub struct InstructionParseError;
 
 impl FromStr for Instruction {
     type Err = InstructionParseError;
@@ -30,23 +35,22 @@ impl FromStr for Instruction {
         match input.split_ascii_whitespace().collect::<Vec<&str>>()[..] {
             ["forward", n_str] => n_str
                 .parse()
-                .map(|n| Instruction::Forwards(n))
+                .map(Instruction::Forwards)
                 .map_err(|_| InstructionParseError),
             ["up", n_str] => n_str
                 .parse()
-                .map(|n| Instruction::Up(n))
+                .map(Instruction::Up)
                 .map_err(|_| InstructionParseError),
             ["down", n_str] => n_str
                 .parse()
-                .map(|n| Instruction::Down(n))
+                .map(Instruction::Down)
                 .map_err(|_| InstructionParseError),
             _ => Err(InstructionParseError),
         }
     }
 }
 
-pub fn part1(input: String) -> isize {
-    let instructions = parse_input(input);
+pub fn part1(instructions: Vec<Instruction>) -> isize {
     let coords = Coordinates { x: 0, y: 0, aim: 0 };
 
     let Coordinates { x, y, aim: _ } =
@@ -70,8 +74,7 @@ pub fn part1(input: String) -> isize {
     return x * y;
 }
 
-pub fn part2(input: String) -> isize {
-    let instructions = parse_input(input);
+pub fn part2(instructions: Vec<Instruction>) -> isize {
     let coords = Coordinates { x: 0, y: 0, aim: 0 };
 
     let Coordinates { x, y, aim: _ } =
@@ -95,3 +98,33 @@ pub fn part2(input: String) -> isize {
 
     return x * y;
 }
+
+/// `size` commands.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    const DIRECTIONS: [&str; 3] = ["forward", "down", "up"];
+    return (0..size)
+        .map(|_| format!("{} {}", rng.choose(&DIRECTIONS), rng.range(1..=9)))
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Instruction>;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    fn parse(input: String) -> Result<Vec<Instruction>> {
+        parse_input(input)
+    }
+    fn part1(instructions: Vec<Instruction>) -> Result<Answer> {
+        Ok(part1(instructions).into())
+    }
+    fn part2(instructions: Vec<Instruction>) -> Result<Answer> {
+        Ok(part2(instructions).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(1000)))
+    }
+}
@@ -0,0 +1,132 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{bail, Result};
+
+pub fn parse_input(input: String) -> Result<Vec<String>> {
+    let numbers: Vec<String> = input::lines(&input).map(|x| x.to_string()).collect();
+    let width = match numbers.first() {
+        Some(first) if !first.is_empty() => first.len(),
+        _ => bail!("The report is empty"),
+    };
+    if let Some(n) = numbers
+        .iter()
+        .find(|n| n.len() != width || !n.chars().all(|c| c == '0' || c == '1'))
+    {
+        bail!("Not a {}-bit binary number: {:?}", width, n);
+    }
+    Ok(numbers)
+}
+
+fn count_ones(numbers: Vec<String>) -> Vec<usize> {
+    let indices = (0..numbers[0].len()).collect::<Vec<_>>();
+    return indices
+        .iter()
+        .copied()
+        .map(|i| {
+            numbers
+                .iter()
+                .map(|n| n.chars().nth(i))
+                .filter(|n| n.unwrap_or('0') == '1')
+                .count()
+        })
+        .collect();
+}
+
+fn inverse_binary_string(number: String) -> String {
+    return number
+        .chars()
+        .map(|c| if c == '1' { '0' } else { '1' })
+        .collect::<String>();
+}
+
+pub fn part1(numbers: Vec<String>) -> Result<usize> {
+    let majority = numbers.len() / 2;
+
+    let ones = count_ones(numbers);
+    let gamma_rate_binary = ones
+        .iter()
+        .cloned()
+        .map(|n| if n >= majority { '1' } else { '0' })
+        .collect::<String>();
+    let epislon_rate_binary = inverse_binary_string(gamma_rate_binary.clone());
+
+    let gamma_rate = usize::from_str_radix(&gamma_rate_binary, 2)?;
+    let epislon_rate = usize::from_str_radix(&epislon_rate_binary, 2)?;
+
+    return Ok(gamma_rate * epislon_rate);
+}
+
+enum BitCriteria {
+    OxygenGeneratorRating,
+    CO2ScrubberRating,
+}
+
+fn find_with_criteria(numbers: Vec<String>, index: usize, criteria: BitCriteria) -> String {
+    if numbers.len() == 1 {
+        return numbers[0].clone();
+    }
+
+    let total = numbers.len();
+    let ones = count_ones(numbers.clone())[index];
+    let zeros = total - ones;
+
+    let selected_char = match criteria {
+        // Every number left shares the bit, so it is also the least common one
+        BitCriteria::CO2ScrubberRating if ones == 0 => '0',
+        BitCriteria::CO2ScrubberRating if zeros == 0 => '1',
+        BitCriteria::OxygenGeneratorRating => if ones >= zeros { '1' } else { '0' }
+        BitCriteria::CO2ScrubberRating => if ones >= zeros { '0' } else { '1' }
+    };
+
+    let remaining_numbers: Vec<String> = numbers
+        .iter().filter(|&n| n.chars().nth(index).unwrap() == selected_char).cloned()
+        .collect();
+
+    return find_with_criteria(remaining_numbers, index + 1, criteria);
+}
+
+pub fn part2(numbers: Vec<String>) -> Result<usize> {
+    let oxygen_generator_rating_binary =
+        find_with_criteria(numbers.clone(), 0, BitCriteria::OxygenGeneratorRating);
+    let co2_scrubber_rating_binary = find_with_criteria(numbers, 0, BitCriteria::CO2ScrubberRating);
+
+    let oxygen_generator_rating = usize::from_str_radix(&oxygen_generator_rating_binary, 2)?;
+    let co2_scrubber_rating = usize::from_str_radix(&co2_scrubber_rating_binary, 2)?;
+
+    return Ok(oxygen_generator_rating * co2_scrubber_rating);
+}
+
+/// `size` distinct 12-bit numbers, up to 4096 of them. The ratings can only be
+/// found when no number repeats, which the real inputs guarantee.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..1 << 12).collect::<Vec<usize>>();
+    rng.shuffle(&mut numbers);
+    return numbers[..size.min(numbers.len())]
+        .iter()
+        .map(|n| format!("{:012b}", n))
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    fn parse(input: String) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+    fn part1(numbers: Vec<String>) -> Result<Answer> {
+        Ok(part1(numbers)?.into())
+    }
+    fn part2(numbers: Vec<String>) -> Result<Answer> {
+        Ok(part2(numbers)?.into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(1000)))
+    }
+}
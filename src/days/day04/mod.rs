@@ -1,32 +1,50 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{bail, Context, Result};
+
 enum GameState {
     Bingo(Game, usize),
     NoBingo(Game),
 }
 #[derive(Clone)]
-struct Game {
+pub struct Game {
     unmarked_numbers: Vec<u16>,
     called_number: u16,
     boards: Vec<Vec<Vec<u16>>>,
 }
-fn parse_input(input: String) -> Game {
-    let chunks = input.split_terminator("\n\n").collect::<Vec<_>>();
-    let numbers = chunks[0].split(',').map(|n| n.parse().unwrap()).collect();
-    let boards = chunks[1..].iter().map(parse_board).collect::<Vec<_>>();
+pub fn parse_input(input: String) -> Result<Game> {
+    let chunks = input::blocks(&input).collect::<Vec<_>>();
+    let numbers = chunks[0]
+        .split(',')
+        .map(|n| n.parse().with_context(|| format!("Invalid called number {:?}", n)))
+        .collect::<Result<_>>()?;
+    let boards = chunks[1..].iter().map(parse_board).collect::<Result<Vec<_>>>()?;
+    if boards.is_empty() {
+        bail!("There are no boards");
+    }
+    if let Some(i) = boards
+        .iter()
+        .position(|board| board.len() != 5 || board.iter().any(|row| row.len() != 5))
+    {
+        bail!("Board {} is not 5 by 5 numbers", i + 1);
+    }
 
-    return Game {
+    return Ok(Game {
         unmarked_numbers: numbers,
-        boards: boards,
+        boards,
         called_number: 999,
-    };
+    });
 }
 
-fn parse_board(input: &&str) -> Vec<Vec<u16>> {
-    let lines = input.split('\n');
+fn parse_board(input: &&str) -> Result<Vec<Vec<u16>>> {
+    let lines = input::lines(input);
     return lines
         .map(|line| {
             line.split(' ')
                 .filter(|s| !s.is_empty())
-                .map(|n| n.parse().unwrap())
+                .map(|n| n.parse().with_context(|| format!("Invalid board number {:?}", n)))
                 .collect()
         })
         .collect();
@@ -99,18 +117,62 @@ fn count_score(state: GameState) -> u16 {
     }
 }
 
-pub fn part1(input: String) -> usize {
-    let game = parse_input(input);
-
+pub fn part1(game: Game) -> usize {
     let score = count_score(play_bingo_till_first(game));
 
     return score.into();
 }
 
-pub fn part2(input: String) -> usize {
-    let game = parse_input(input);
-
+pub fn part2(game: Game) -> usize {
     let score = count_score(play_bingo_till_last(game));
 
     return score.into();
 }
+
+/// Every number from 0 to 99 is called, so each of the `size` boards wins eventually.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..100).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    let mut blocks = vec![numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",")];
+
+    for _ in 0..size {
+        rng.shuffle(&mut numbers);
+        let board = numbers[..25]
+            .chunks(5)
+            .map(|row| {
+                row.iter()
+                    .map(|n| format!("{:>2}", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        blocks.push(board);
+    }
+    return blocks.join("\n\n");
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Game;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    fn parse(input: String) -> Result<Game> {
+        parse_input(input)
+    }
+    fn part1(game: Game) -> Result<Answer> {
+        Ok(part1(game).into())
+    }
+    fn part2(game: Game) -> Result<Answer> {
+        Ok(part2(game).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(100)))
+    }
+}
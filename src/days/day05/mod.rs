@@ -0,0 +1,116 @@
+use crate::Vec2;
+use crate::aabb::Rect;
+use crate::answer::Answer;
+use crate::input;
+use crate::line::{End, Points, Segment};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+
+const SIZE: isize = 1000;
+
+/// A line of vents, at any angle.
+#[derive(Clone)]
+pub struct Line(Segment<2>);
+
+impl IntoIterator for Line {
+    type Item = Vec2;
+    type IntoIter = Points<2>;
+    fn into_iter(self) -> <Self as std::iter::IntoIterator>::IntoIter {
+        return self.0.points(End::Inclusive);
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Line>> {
+    let board = Rect::new(Vec2::default(), Vec2::new(SIZE - 1, SIZE - 1));
+    return input::lines(&input)
+        .map(|text_line| {
+            let (a, b) = text_line
+                .split_once(" -> ")
+                .ok_or(anyhow!("Invalid line {:?}", text_line))?;
+            let segment = Segment::new(a.parse()?, b.parse()?);
+            if !board.contains(&segment.from) || !board.contains(&segment.to) {
+                bail!("Line {:?} is off the {}x{} board", text_line, SIZE, SIZE);
+            }
+            Ok(Line(segment))
+        })
+        .collect();
+}
+
+fn count_intersections(lines: Vec<Line>) -> usize {
+    let mut board = vec![vec![0i16; SIZE as usize]; SIZE as usize];
+    let mut count = 0;
+
+    for line in lines {
+        for point in line {
+            board[point.y() as usize][point.x() as usize] += 1;
+        }
+    }
+
+    for row in board {
+        for val in row {
+            if val > 1 {
+                count += 1;
+            }
+        }
+    }
+
+    return count;
+}
+
+pub fn part1(lines: Vec<Line>) -> usize {
+    let straight_lines = lines
+        .iter()
+        .filter(|line| line.0.is_axis_aligned())
+        .cloned()
+        .collect();
+
+    return count_intersections(straight_lines);
+}
+
+pub fn part2(lines: Vec<Line>) -> usize {
+    return count_intersections(lines);
+}
+
+/// `size` horizontal, vertical or diagonal lines that fit on the 1000x1000 board.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    return (0..size)
+        .map(|_| {
+            let (x1, y1) = (rng.range(0..=999), rng.range(0..=999));
+            let (x2, y2) = match rng.below(3) {
+                0 => (rng.range(0..=999), y1),
+                1 => (x1, rng.range(0..=999)),
+                _ => {
+                    let (dx, dy) = (*rng.choose(&[-1, 1]), *rng.choose(&[-1, 1]));
+                    let room_x = if dx > 0 { 999 - x1 } else { x1 };
+                    let room_y = if dy > 0 { 999 - y1 } else { y1 };
+                    let length = rng.range(0..=room_x.min(room_y));
+                    (x1 + dx * length, y1 + dy * length)
+                }
+            };
+            format!("{},{} -> {},{}", x1, y1, x2, y2)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    fn parse(input: String) -> Result<Vec<Line>> {
+        parse_input(input)
+    }
+    fn part1(lines: Vec<Line>) -> Result<Answer> {
+        Ok(part1(lines).into())
+    }
+    fn part2(lines: Vec<Line>) -> Result<Answer> {
+        Ok(part2(lines).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(500)))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{bail, Result};
+
+pub fn parse_input(input: String) -> Result<Vec<usize>> {
+    let fish = input
+        .split(',')
+        .map(|n| n.parse())
+        .collect::<Result<Vec<usize>, _>>()?;
+    if let Some(timer) = fish.iter().find(|&&timer| timer > 8) {
+        bail!("Timer {} is out of range", timer);
+    }
+    return Ok(fish);
+}
+
+fn count_fish(fish: Vec<usize>, days: usize) -> usize {
+    let mut school = [0; 9];
+    for i in fish {
+        school[i] += 1;
+    }
+
+    for i in (0..9).cycle().take(days) {
+        school[(i + 7) % 9] += school[i];
+    }
+
+    return school.iter().sum();
+}
+
+pub fn part1(fish: Vec<usize>) -> usize {
+    return count_fish(fish, 80);
+}
+pub fn part2(fish: Vec<usize>) -> usize {
+    return count_fish(fish, 256);
+}
+
+/// `size` lanternfish, with timers from 1 to 5 like the real inputs.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    return (0..size)
+        .map(|_| rng.range(1..=5).to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    fn parse(input: String) -> Result<Vec<usize>> {
+        parse_input(input)
+    }
+    fn part1(fish: Vec<usize>) -> Result<Answer> {
+        Ok(part1(fish).into())
+    }
+    fn part2(fish: Vec<usize>) -> Result<Answer> {
+        Ok(part2(fish).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(300)))
+    }
+}
@@ -0,0 +1,76 @@
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::Result;
+
+pub fn parse_input(input: String) -> Result<Vec<isize>> {
+    let crabs = input
+        .split(',')
+        .map(|n| n.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+    return Ok(crabs);
+}
+
+fn sum_of_first_n_numbers(n: isize) -> isize {
+    // return n * (n + 1) / 2;
+    return (1..=n).sum();
+}
+
+fn find_minimal_fuel_consumption<F: Fn(isize) -> isize>(
+    crabs: Vec<isize>,
+    fuel_cost_getter: F,
+) -> isize {
+    let &max = crabs.iter().max().unwrap();
+    let &min = crabs.iter().min().unwrap();
+
+    return (min..=max)
+        .map(|target| {
+            crabs
+                .iter()
+                .map(|crab| fuel_cost_getter((target - crab).abs()))
+                .sum()
+        })
+        .min()
+        .unwrap();
+}
+
+pub fn part1(crabs: Vec<isize>) -> usize {
+    return find_minimal_fuel_consumption(crabs, std::convert::identity)
+        .try_into()
+        .unwrap();
+}
+
+pub fn part2(crabs: Vec<isize>) -> usize {
+    return find_minimal_fuel_consumption(crabs, sum_of_first_n_numbers)
+        .try_into()
+        .unwrap();
+}
+
+/// `size` crabs, spread over twice as many positions.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    return (0..size)
+        .map(|_| rng.below(2 * size).to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<isize>;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    fn parse(input: String) -> Result<Vec<isize>> {
+        parse_input(input)
+    }
+    fn part1(crabs: Vec<isize>) -> Result<Answer> {
+        Ok(part1(crabs).into())
+    }
+    fn part2(crabs: Vec<isize>) -> Result<Answer> {
+        Ok(part2(crabs).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(1000)))
+    }
+}
@@ -0,0 +1,178 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Clone, Debug)]
+pub struct Display {
+  wire_signals: Vec<HashSet<char>>,
+  display: Vec<HashSet<char>>,
+}
+
+impl FromStr for Display {
+  type Err = anyhow::Error;
+  fn from_str(line: &str) -> Result<Self, <Self as FromStr>::Err> {
+    match line.split_once(" | ") {
+      None => Err(anyhow!("Failed to parse {}", line)),
+      Some((wire_signals, display)) => {
+        let parsed_digits = wire_signals
+          .split(' ')
+          .map(|digit| digit.chars().collect())
+          .collect();
+        let parsed_display = display
+          .split(' ')
+          .map(|digit| digit.chars().collect())
+          .collect();
+
+        return Ok(Display {
+          wire_signals: parsed_digits,
+          display: parsed_display,
+        });
+      }
+    }
+  }
+}
+
+fn deduce_number(
+  Display {
+    wire_signals,
+    display,
+  }: Display,
+) -> Result<usize> {
+  let mut mapping: [HashSet<char>; 10] = Default::default();
+
+  for pattern in wire_signals.clone() {
+    match pattern.len() {
+      2 => mapping[1] = pattern,
+      3 => mapping[7] = pattern,
+      4 => mapping[4] = pattern,
+      7 => mapping[8] = pattern,
+      _ => (),
+    }
+  }
+
+  for pattern in wire_signals.clone() {
+    if pattern.len() != 5 {
+      continue;
+    }
+    if mapping[1].difference(&pattern).count() == 0 {
+      mapping[3] = pattern;
+    } else if pattern.difference(&mapping[4]).count() == 2 {
+      mapping[5] = pattern;
+    } else if pattern.difference(&mapping[4]).count() == 3 {
+      mapping[2] = pattern;
+    }
+  }
+
+  for pattern in wire_signals {
+    if pattern.len() == 6 {
+      if mapping[1].difference(&pattern).count() == 1 {
+        mapping[6] = pattern;
+      } else if mapping[5].difference(&pattern).count() == 0 {
+        mapping[9] = pattern;
+      } else {
+        mapping[0] = pattern;
+      }
+    }
+  }
+
+  let unscrambled_number = display
+    .iter()
+    .rev() // I want increasing powers!
+    .enumerate()
+    .try_fold(0, |acc, (i, scrambled_digit)| {
+      let digit = mapping
+        .iter()
+        .position(|potential_digit| potential_digit == scrambled_digit)
+        .ok_or(anyhow!(
+          "Digit {} of the display matches none of the patterns",
+          display.len() - i
+        ))?;
+
+      return Ok(acc + digit * usize::pow(10, i as u32));
+    });
+
+  return unscrambled_number;
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Display>> {
+  return input::lines(&input).map(Display::from_str).collect();
+}
+
+pub fn part1(displays: Vec<Display>) -> usize {
+  return displays
+    .iter()
+    .map(|Display { display, .. }| {
+      display
+        .iter()
+        .filter(|digit| [2, 3, 4, 7].contains(&digit.len()))
+        .count()
+    })
+    .sum();
+}
+
+pub fn part2(displays: Vec<Display>) -> Result<usize> {
+  return displays
+    .iter()
+    .map(|display| deduce_number(display.clone()))
+    .sum();
+}
+
+const SEGMENTS: [&str; 10] = [
+  "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn scramble(rng: &mut Rng, wiring: &[char], digit: usize) -> String {
+  let mut segments = SEGMENTS[digit]
+    .bytes()
+    .map(|segment| wiring[(segment - b'a') as usize])
+    .collect::<Vec<_>>();
+  rng.shuffle(&mut segments);
+  return segments.into_iter().collect();
+}
+
+/// `size` displays, each wired at random.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  return (0..size)
+    .map(|_| {
+      let mut wiring = "abcdefg".chars().collect::<Vec<_>>();
+      rng.shuffle(&mut wiring);
+      let mut patterns = (0..10)
+        .map(|digit| scramble(rng, &wiring, digit))
+        .collect::<Vec<_>>();
+      rng.shuffle(&mut patterns);
+      let output = (0..4)
+        .map(|_| {
+          let digit = rng.below(10);
+          scramble(rng, &wiring, digit)
+        })
+        .collect::<Vec<_>>();
+      format!("{} | {}", patterns.join(" "), output.join(" "))
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+  type Input = Vec<Display>;
+  const DAY: u8 = 8;
+  const TITLE: &'static str = "Seven Segment Search";
+
+  fn parse(input: String) -> Result<Vec<Display>> {
+    parse_input(input)
+  }
+  fn part1(displays: Vec<Display>) -> Result<Answer> {
+    Ok(part1(displays).into())
+  }
+  fn part2(displays: Vec<Display>) -> Result<Answer> {
+    Ok(part2(displays)?.into())
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(200)))
+  }
+}
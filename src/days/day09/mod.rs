@@ -0,0 +1,109 @@
+use crate::Vec2;
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub fn parse_input(input: String) -> Result<Grid<usize>> {
+    return Grid::parse_digits(&input);
+}
+
+fn find_local_minima(board: &Grid<usize>) -> Vec<Vec2> {
+    return board
+        .iter()
+        .filter(|&(pos, &val)| board.neighbors4(pos).all(|around| val < board[around]))
+        .map(|(pos, _)| pos)
+        .collect();
+}
+
+fn find_basin_size(board: &Grid<usize>, local_minimum: Vec2) -> usize {
+    let mut found: HashSet<Vec2> = HashSet::from([local_minimum]);
+    let mut to_visit: Vec<Vec2> = vec![local_minimum];
+
+    let mut i = 0;
+    while i < to_visit.len() {
+        for around in board.neighbors4(to_visit[i]) {
+            if board[around] == 9 || found.contains(&around) {
+                continue;
+            }
+
+            found.insert(around);
+            to_visit.push(around);
+        }
+
+        i += 1;
+    }
+
+    return to_visit.len();
+}
+
+pub fn part1(board: Grid<usize>) -> usize {
+    return find_local_minima(&board)
+        .iter()
+        .map(|&pos| board[pos] + 1)
+        .sum();
+}
+
+pub fn part2(board: Grid<usize>) -> usize {
+    let mut basins = find_local_minima(&board)
+        .iter()
+        .map(|&pos| find_basin_size(&board, pos))
+        .collect::<Vec<_>>();
+
+    basins.sort_by(|a, b| b.cmp(a));
+
+    return basins.iter().take(3).product();
+}
+
+/// A `size` by `size` heightmap. Like in the real inputs, the basins are walled off
+/// by 9s, each around a low point.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let low_points = (0..(size * size / 50).max(1))
+        .map(|_| (rng.below(size.max(1)), rng.below(size.max(1))))
+        .collect::<Vec<_>>();
+    let nearest_low_point = |x: usize, y: usize| {
+        return low_points
+            .iter()
+            .map(|&(low_x, low_y)| x.abs_diff(low_x) + y.abs_diff(low_y))
+            .enumerate()
+            .min_by_key(|&(_, distance)| distance)
+            .unwrap();
+    };
+
+    return (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    let (basin, distance) = nearest_low_point(x, y);
+                    let on_border = (x + 1 < size && nearest_low_point(x + 1, y).0 != basin)
+                        || (y + 1 < size && nearest_low_point(x, y + 1).0 != basin);
+                    if on_border { 9 } else { distance.min(8) }.to_string()
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid<usize>;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    fn parse(input: String) -> Result<Grid<usize>> {
+        parse_input(input)
+    }
+    fn part1(board: Grid<usize>) -> Result<Answer> {
+        Ok(part1(board).into())
+    }
+    fn part2(board: Grid<usize>) -> Result<Answer> {
+        Ok(part2(board).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(100)))
+    }
+}
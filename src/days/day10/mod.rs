@@ -0,0 +1,158 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{bail, Result};
+
+pub fn parse_input(input: String) -> Result<Vec<String>> {
+  if let Some(c) = input.chars().find(|c| !"()[]{}<>\n".contains(*c)) {
+    bail!("Invalid character {:?}", c);
+  }
+  return Ok(input::lines(&input).map(|s| s.to_string()).collect());
+}
+
+enum ChunkState {
+  Valid,
+  Invalid(char),
+  Incomplete(Vec<char>),
+}
+
+fn syntax_check(chunk: &str) -> ChunkState {
+  let mut stack: Vec<char> = Vec::default();
+  for c in chunk.chars() {
+    match c {
+      '(' | '[' | '{' | '<' => stack.push(c),
+      ')' | ']' | '}' | '>' => {
+        let open_c_option = stack.pop();
+        if open_c_option.is_none() {
+          return ChunkState::Invalid(c);
+        }
+        let open_c = open_c_option.unwrap();
+        if (open_c == '(' && c != ')')
+          || (open_c == '[' && c != ']')
+          || (open_c == '{' && c != '}')
+          || (open_c == '<' && c != '>')
+        {
+          return ChunkState::Invalid(c);
+        }
+      }
+      _ => return ChunkState::Invalid(c),
+    }
+  }
+  if !stack.is_empty() {
+    return ChunkState::Incomplete(stack);
+  }
+  return ChunkState::Valid;
+}
+
+fn score(c: char) -> usize {
+  match c {
+    ')' => 3,
+    ']' => 57,
+    '}' => 1197,
+    '>' => 25137,
+    _ => panic!("Invalid character in scoring"),
+  }
+}
+
+fn score_missing(mut incomplete_stack: Vec<char>) -> usize {
+  incomplete_stack.reverse();
+  let mut score = 0;
+
+  for c in incomplete_stack {
+    score *= 5;
+    score += match c {
+      '(' => 1,
+      '[' => 2,
+      '{' => 3,
+      '<' => 4,
+      _ => panic!("Invalid character in scoring"),
+    }
+  }
+
+  return score;
+}
+
+pub fn part1(chunks: Vec<String>) -> usize {
+  return chunks
+    .iter()
+    .map(|chunk| syntax_check(chunk))
+    .filter_map(|result| match result {
+      ChunkState::Invalid(c) => Some(c),
+      _ => None,
+    })
+    .map(score)
+    .sum();
+}
+
+pub fn part2(chunks: Vec<String>) -> Result<usize> {
+  let mut scores = chunks
+    .iter()
+    .map(|chunk| syntax_check(chunk))
+    .filter_map(|result| match result {
+      ChunkState::Incomplete(remaining) => Some(remaining),
+      _ => None,
+    })
+    .map(score_missing)
+    .collect::<Vec<_>>();
+
+  if scores.is_empty() {
+    bail!("No line is incomplete");
+  }
+  scores.sort();
+
+  return Ok(scores[scores.len() / 2]);
+}
+
+/// `size` lines, about half of them corrupted and the rest incomplete.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+  return (0..size)
+    .map(|_| {
+      let mut line = String::new();
+      let mut expected = Vec::new();
+      for _ in 0..rng.range(20..=110) {
+        if expected.is_empty() || rng.chance(3, 5) {
+          let &(open, close) = rng.choose(&PAIRS);
+          line.push(open);
+          expected.push(close);
+        } else {
+          line.extend(expected.pop());
+        }
+      }
+      if rng.chance(1, 2) {
+        let wrong = PAIRS
+          .iter()
+          .map(|&(_, close)| close)
+          .filter(|&close| expected.last() != Some(&close))
+          .collect::<Vec<_>>();
+        line.push(*rng.choose(&wrong));
+      } else if expected.is_empty() {
+        line.push(rng.choose(&PAIRS).0);
+      }
+      line
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  type Input = Vec<String>;
+  const DAY: u8 = 10;
+  const TITLE: &'static str = "Syntax Scoring";
+
+  fn parse(input: String) -> Result<Vec<String>> {
+    parse_input(input)
+  }
+  fn part1(chunks: Vec<String>) -> Result<Answer> {
+    Ok(part1(chunks).into())
+  }
+  fn part2(chunks: Vec<String>) -> Result<Answer> {
+    Ok(part2(chunks)?.into())
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(100)))
+  }
+}
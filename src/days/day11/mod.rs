@@ -0,0 +1,144 @@
+use crate::Vec2;
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::{Part, Solution};
+use crate::visualize::{simulate, Frames};
+use anyhow::Result;
+use std::collections::HashSet;
+
+type Board = Grid<usize>;
+
+pub fn parse_input(input: String) -> Result<Board> {
+  return Grid::parse_digits(&input);
+}
+
+fn simulate_step(board: &mut Board) -> usize {
+  let mut flashed: HashSet<Vec2> = HashSet::default();
+  // Gather energy
+  for value in board.values_mut() {
+    *value += 1;
+  }
+  // Flash cascade!
+  let positions = board.positions().collect::<Vec<_>>();
+  while board.values().any(|&v| v > 9) {
+    for &pos in &positions {
+      if board[pos] > 9 {
+        board[pos] = 0;
+        flashed.insert(pos);
+        for adj in board.neighbors8(pos).collect::<Vec<_>>() {
+          if !flashed.contains(&adj) {
+            board[adj] += 1;
+          }
+        }
+      }
+    }
+  }
+  return flashed.len();
+}
+
+pub fn part1(mut board: Board) -> usize {
+  let mut flashed = 0;
+  for _ in 0..100 {
+    let step_flashed = simulate_step(&mut board);
+    flashed += step_flashed;
+  }
+
+  return flashed;
+}
+
+pub fn part2(mut board: Board) -> usize {
+  let mut i = 0;
+  loop {
+    i += 1;
+    let step_flashed = simulate_step(&mut board);
+    if step_flashed == board.len() {
+      return i;
+    }
+  }
+}
+
+/// A `size` by `size` grid of octopuses. Unlike the real inputs, a random one
+/// isn't guaranteed to ever synchronize.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  return (0..size)
+    .map(|_| (0..size).map(|_| rng.range(0..=9).to_string()).collect::<String>())
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+  type Input = Board;
+  const DAY: u8 = 11;
+  const TITLE: &'static str = "Dumbo Octopus";
+
+  fn parse(input: String) -> Result<Board> {
+    parse_input(input)
+  }
+  fn part1(board: Board) -> Result<Answer> {
+    Ok(part1(board).into())
+  }
+  fn part2(board: Board) -> Result<Answer> {
+    Ok(part2(board).into())
+  }
+  fn visualize(board: Board, part: Part) -> Result<Option<Frames>> {
+    let mut steps = 0;
+    let mut synchronized = false;
+    let step = move |board: &mut Board| {
+      let done = match part {
+        Part::One => steps == 100,
+        Part::Two => synchronized,
+      };
+      if !done {
+        steps += 1;
+        synchronized = simulate_step(board) == board.len();
+      }
+      !done
+    };
+    Ok(Some(simulate(board, step, Board::to_string)))
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(10)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn part1_test() {
+    let sample_input = String::from(
+      "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526",
+    );
+    assert_eq!(part1(parse_input(sample_input).unwrap()), 1656);
+  }
+
+  #[test]
+  fn part2_test() {
+    let sample_input = String::from(
+      "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526",
+    );
+    assert_eq!(part2(parse_input(sample_input).unwrap()), 195);
+  }
+}
@@ -0,0 +1,192 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashSet;
+
+enum CaveKind {
+    Start,
+    End,
+    Small,
+    Large,
+}
+impl CaveKind {
+    pub fn from(cave_name: &str) -> Self {
+        match cave_name {
+            "start" => Self::Start,
+            "end" => Self::End,
+            cave => {
+                if cave.to_ascii_lowercase() == cave {
+                    Self::Small
+                } else {
+                    Self::Large
+                }
+            }
+        }
+    }
+}
+
+pub struct Cave {
+    name: String,
+    links: Vec<String>,
+}
+
+impl From<&str> for Cave {
+    fn from(input: &str) -> Self {
+        Self {
+            name: input.to_string(),
+            links: Vec::new(),
+        }
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Cave>> {
+    let mut came_across: HashSet<&str> = HashSet::default();
+    let mut caves = Vec::default();
+    let lines = input::lines(&input)
+        .map(|line| line.split_once('-').ok_or(anyhow!("Invalid link {:?}", line)))
+        .collect::<Result<Vec<_>>>()?;
+    for &(cave_a, cave_b) in &lines {
+        for cave in [cave_a, cave_b] {
+            if came_across.contains(cave) {
+                continue;
+            }
+            caves.push(Cave::from(cave));
+            came_across.insert(cave);
+        }
+    }
+
+    if !came_across.contains("start") {
+        bail!("There is no start cave");
+    }
+
+    for &(cave_a, cave_b) in &lines {
+        caves
+            .iter_mut()
+            .filter(|c| c.name == cave_a)
+            .for_each(|c| c.links.push(cave_b.to_string()));
+        caves
+            .iter_mut()
+            .filter(|c| c.name == cave_b)
+            .for_each(|c| c.links.push(cave_a.to_string()));
+    }
+
+    return Ok(caves);
+}
+
+fn any_small_cave_revisited_twice(path: &str) -> bool {
+    let mut visited: HashSet<&str> = HashSet::default();
+    for small_cave in path.split('-').filter(|c| matches!(CaveKind::from(c), CaveKind::Small)) {
+        if visited.contains(small_cave) {
+            return true;
+        }
+        visited.insert(small_cave);
+    }
+    return false;
+}
+
+fn count_paths(caves: &[Cave], allow_revisits: bool) -> usize {
+    let mut paths: HashSet<String> = HashSet::default();
+    let mut to_visit: Vec<String> = Vec::default();
+    to_visit.push("start".to_string());
+
+    while let Some(current_path) = to_visit.pop() {
+
+        let current_name = current_path.split("-").last().unwrap();
+        let current_cave = caves
+            .iter()
+            .find(|c| c.name == current_name)
+            .unwrap();
+
+        for link in &current_cave.links {
+            let new_path = format!("{}-{}", current_path, link);
+
+            match CaveKind::from(link) {
+                CaveKind::Start => {} // Revisited start, path dies
+                CaveKind::End => {
+                    paths.insert(new_path);
+                } // Visited end, path completed!
+                CaveKind::Large => to_visit.push(new_path), // Visited large cave, path continues
+                CaveKind::Small => {
+                    let small_cave_visited_count =
+                        current_path.split('-').filter(|c| c == link).count();
+
+                    match small_cave_visited_count {
+                        0 => to_visit.push(new_path),
+                        1 if allow_revisits && !any_small_cave_revisited_twice(&current_path) => {
+                            to_visit.push(new_path)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    return paths.len();
+}
+
+pub fn part1(caves: Vec<Cave>) -> usize {
+    return count_paths(&caves, false);
+}
+
+pub fn part2(caves: Vec<Cave>) -> usize {
+    return count_paths(&caves, true);
+}
+
+/// `size` caves besides `start` and `end`, up to 676 of them. Two big caves are
+/// never linked, or there would be infinitely many paths.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut names = vec!["start".to_string(), "end".to_string()];
+    for i in 0..size.min(26 * 26) {
+        let name = [b'a' + (i / 26) as u8, b'a' + (i % 26) as u8];
+        let name = String::from_utf8_lossy(&name).into_owned();
+        names.push(if rng.chance(1, 4) { name.to_uppercase() } else { name });
+    }
+    rng.shuffle(&mut names[1..]);
+
+    let is_big = |i: usize| names[i].chars().all(|c| c.is_ascii_uppercase());
+    let mut links = HashSet::new();
+    // Link every cave to an earlier one so that they are all connected. `start` is
+    // first and small, so there is always one to pick
+    for i in 1..names.len() {
+        let candidates = (0..i).filter(|&j| !is_big(i) || !is_big(j)).collect::<Vec<_>>();
+        links.insert((*rng.choose(&candidates), i));
+    }
+    for _ in 0..names.len() {
+        let (a, b) = (rng.below(names.len()), rng.below(names.len()));
+        if a < b && (!is_big(a) || !is_big(b)) {
+            links.insert((a, b));
+        }
+    }
+
+    let mut links = links.into_iter().collect::<Vec<_>>();
+    links.sort();
+    rng.shuffle(&mut links);
+    return links
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}", names[a], names[b]))
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Cave>;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    fn parse(input: String) -> Result<Vec<Cave>> {
+        parse_input(input)
+    }
+    fn part1(caves: Vec<Cave>) -> Result<Answer> {
+        Ok(part1(caves).into())
+    }
+    fn part2(caves: Vec<Cave>) -> Result<Answer> {
+        Ok(part2(caves).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(8)))
+    }
+}
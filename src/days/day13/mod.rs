@@ -0,0 +1,213 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::ocr;
+use crate::rng::Rng;
+use crate::solution::{Part, Solution};
+use crate::visualize::{simulate, Frames};
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+enum Fold {
+  X(usize),
+  Y(usize),
+}
+
+#[derive(Debug)]
+pub struct Board {
+  points: HashMap<(usize, usize), usize>,
+  folds: VecDeque<Fold>,
+}
+
+pub fn parse_input(input: String) -> Result<Board> {
+  let (points_str, folds_str) = input::split_blocks_once(&input)
+    .ok_or(anyhow!("Expected a blank line between the dots and the folds"))?;
+
+  let points: HashMap<(usize, usize), usize> = input::lines(points_str)
+    .map(|coords| {
+      let (x, y) = coords
+        .split_once(',')
+        .ok_or(anyhow!("Invalid dot {:?}", coords))?;
+      Ok(((x.parse()?, y.parse()?), 1))
+    })
+    .collect::<Result<_>>()?;
+
+  let folds: VecDeque<Fold> = input::lines(folds_str)
+    .map(|fold| match fold.split_once('=') {
+      Some(("fold along x", n)) => Ok(Fold::X(n.parse()?)),
+      Some(("fold along y", n)) => Ok(Fold::Y(n.parse()?)),
+      _ => Err(anyhow!("Invalid fold {:?}", fold)),
+    })
+    .collect::<Result<_>>()?;
+
+  if points.is_empty() {
+    bail!("There are no dots");
+  }
+  if folds.is_empty() {
+    bail!("There are no folds");
+  }
+  if let Some(&(x, y)) = points.keys().find(|&&dot| fold_dot(dot, &folds).is_none()) {
+    bail!("Dot {},{} lands on a fold line or off the sheet", x, y);
+  }
+
+  return Ok(Board { points, folds });
+}
+
+/// Where a dot ends up after all the folds, or `None` when it lands on a fold line
+/// or folds past the edge of the sheet on the way.
+fn fold_dot<'a>(
+  (mut x, mut y): (usize, usize),
+  folds: impl IntoIterator<Item = &'a Fold>,
+) -> Option<(usize, usize)> {
+  for fold in folds {
+    let (coord, line) = match *fold {
+      Fold::X(fold_x) => (&mut x, fold_x),
+      Fold::Y(fold_y) => (&mut y, fold_y),
+    };
+    if *coord == line || *coord > 2 * line {
+      return None;
+    }
+    if *coord > line {
+      *coord = 2 * line - *coord;
+    }
+  }
+  Some((x, y))
+}
+
+fn fold_once(board: &mut Board) {
+  let fold = board.folds.pop_front().unwrap();
+  match fold {
+    Fold::X(fold_x) => {
+      for ((x, y), value) in board.points.clone() {
+        if x < fold_x {
+          continue;
+        }
+        let d = x - fold_x;
+        board.points.insert(
+          (x - (2 * d), y),
+          board.points.get(&(x - (2 * d), y)).unwrap_or(&0) + value,
+        );
+        board.points.remove(&(x, y));
+      }
+    }
+    Fold::Y(fold_y) => {
+      for ((x, y), value) in board.points.clone() {
+        if y < fold_y {
+          continue;
+        }
+        let d = y - fold_y;
+        board.points.insert(
+          (x, y - (2 * d)),
+          board.points.get(&(x, y - (2 * d))).unwrap_or(&0) + value,
+        );
+        board.points.remove(&(x, y));
+      }
+    }
+  }
+}
+
+fn render_board(Board { points, .. }: &Board) -> String {
+  let &max_x = points.keys().map(|(x, _)| x).max().unwrap();
+  let &max_y = points.keys().map(|(_, y)| y).max().unwrap();
+
+  return (0..=max_y)
+    .map(|y| {
+      (0..=max_x)
+        .map(|x| match points.get(&(x, y)) {
+          Some(_) => '#',
+          None => ' ',
+        })
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub fn part1(mut board: Board) -> usize {
+  fold_once(&mut board);
+
+  return board.points.len();
+}
+
+pub fn part2(mut board: Board) -> String {
+  while !board.folds.is_empty() {
+    fold_once(&mut board);
+  }
+
+  // Fall back to the picture when the letters aren't in the known font
+  return ocr::recognize_points(board.points.keys().copied())
+    .unwrap_or_else(|_| render_board(&board));
+}
+
+/// `size` dots on a sheet that folds in half along each fold, like the real inputs.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  // Each fold doubles the sheet from where it ends up, plus the fold line itself
+  let mut width = rng.range(5..=40);
+  let mut height = rng.range(5..=10);
+  let mut folds = Vec::new();
+  for _ in 0..rng.range(2..=12) {
+    if rng.chance(1, 2) {
+      folds.push(Fold::X(width as usize));
+      width = 2 * width + 1;
+    } else {
+      folds.push(Fold::Y(height as usize));
+      height = 2 * height + 1;
+    }
+  }
+  folds.reverse();
+
+  let mut dots = Vec::new();
+  while dots.len() < size {
+    let (x, y) = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+    if fold_dot((x as usize, y as usize), &folds).is_some() {
+      dots.push(format!("{},{}", x, y));
+    }
+  }
+  let folds = folds
+    .iter()
+    .map(|fold| match fold {
+      Fold::X(x) => format!("fold along x={}", x),
+      Fold::Y(y) => format!("fold along y={}", y),
+    })
+    .collect::<Vec<_>>();
+  return format!("{}\n\n{}", dots.join("\n"), folds.join("\n"));
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+  type Input = Board;
+  const DAY: u8 = 13;
+  const TITLE: &'static str = "Transparent Origami";
+
+  fn parse(input: String) -> Result<Board> {
+    parse_input(input)
+  }
+  fn part1(board: Board) -> Result<Answer> {
+    Ok(part1(board).into())
+  }
+  fn part2(board: Board) -> Result<Answer> {
+    Ok(part2(board).into())
+  }
+  fn visualize(board: Board, part: Part) -> Result<Option<Frames>> {
+    let mut folds_left = match part {
+      Part::One => 1,
+      Part::Two => board.folds.len(),
+    };
+    let step = move |board: &mut Board| {
+      if folds_left == 0 {
+        return false;
+      }
+      folds_left -= 1;
+      fold_once(board);
+      true
+    };
+    Ok(Some(simulate(board, step, render_board)))
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(800)))
+  }
+}
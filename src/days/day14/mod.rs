@@ -0,0 +1,159 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default, Debug)]
+pub struct Polymer {
+    occurences: HashMap<[char; 2], usize>,
+    instructions: HashMap<[char; 2], char>,
+}
+
+pub fn parse_input(input: String) -> Result<(Polymer, char)> {
+    let (template, instruction_strs) = input::split_blocks_once(&input)
+        .ok_or(anyhow!("Expected a blank line between the template and the rules"))?;
+    let instructions = input::lines(instruction_strs)
+        .map(|inst| {
+            let (portion, c) = inst
+                .split_once(" -> ")
+                .ok_or(anyhow!("Invalid rule {:?}", inst))?;
+            let pair = portion.chars().collect::<Vec<_>>();
+            let inserted = c.chars().collect::<Vec<_>>();
+            match (&pair[..], &inserted[..]) {
+                (&[a, b], &[c]) => Ok(([a, b], c)),
+                _ => Err(anyhow!("Invalid rule {:?}", inst)),
+            }
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    let mut occurences: HashMap<[char; 2], usize> = HashMap::default();
+    for slice in template.chars().collect::<Vec<_>>().windows(2) {
+        *occurences.entry([slice[0], slice[1]]).or_default() += 1;
+    }
+
+    // Every pair in the template, and every pair an insertion makes, needs a rule
+    let mut to_visit: Vec<[char; 2]> = occurences.keys().copied().collect();
+    let mut found: HashSet<[char; 2]> = to_visit.iter().copied().collect();
+    while let Some([first, last]) = to_visit.pop() {
+        let mid = *instructions
+            .get(&[first, last])
+            .ok_or(anyhow!("There is no rule for the pair {}{}", first, last))?;
+        for pair in [[first, mid], [mid, last]] {
+            if found.insert(pair) {
+                to_visit.push(pair);
+            }
+        }
+    }
+
+    return Ok((
+        Polymer {
+            occurences,
+            instructions,
+        },
+        template.chars().last().ok_or(anyhow!("The template is empty"))?,
+    ));
+}
+
+fn polymize(
+    Polymer {
+        occurences,
+        instructions,
+    }: Polymer,
+) -> Polymer {
+    let mut new_occurences = HashMap::default();
+
+    for ([first, last], v) in occurences {
+        let mid = instructions[&[first, last]];
+        *new_occurences.entry([first, mid]).or_default() += v;
+        *new_occurences.entry([mid, last]).or_default() += v;
+    }
+
+    return Polymer {
+        occurences: new_occurences,
+        instructions,
+    };
+}
+
+fn count_letters(
+    occurences: HashMap<[char; 2], usize>,
+    last_character: char,
+) -> HashMap<char, usize> {
+    let mut result = HashMap::default();
+
+    for ([first, _], count) in occurences {
+        *result.entry(first).or_default() += count;
+    }
+
+    *result.entry(last_character).or_default() += 1;
+
+    return result;
+}
+
+fn count_min_max((mut polymer, last_character): (Polymer, char), iterations: usize) -> usize {
+    for _ in 0..iterations {
+        polymer = polymize(polymer);
+    }
+
+    let letter_counts = count_letters(polymer.occurences, last_character);
+
+    let (_, &min) = letter_counts
+        .iter()
+        .min_by(|(_, a), (_, b)| a.cmp(b))
+        .unwrap();
+    let (_, &max) = letter_counts
+        .iter()
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .unwrap();
+
+    return max - min;
+}
+
+pub fn part1(polymer: (Polymer, char)) -> usize {
+    return count_min_max(polymer, 10);
+}
+
+pub fn part2(polymer: (Polymer, char)) -> usize {
+    return count_min_max(polymer, 40);
+}
+
+/// A template of `size` elements, with a rule for every pair of elements.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut elements = ('A'..='Z').collect::<Vec<_>>();
+    rng.shuffle(&mut elements);
+    elements.truncate(rng.range(2..=10) as usize);
+
+    let template = (0..size.max(1))
+        .map(|_| *rng.choose(&elements))
+        .collect::<String>();
+    let mut rules = Vec::new();
+    for &a in &elements {
+        for &b in &elements {
+            rules.push(format!("{}{} -> {}", a, b, rng.choose(&elements)));
+        }
+    }
+    rng.shuffle(&mut rules);
+    return format!("{}\n\n{}", template, rules.join("\n"));
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Polymer, char);
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    fn parse(input: String) -> Result<(Polymer, char)> {
+        parse_input(input)
+    }
+    fn part1(polymer: (Polymer, char)) -> Result<Answer> {
+        Ok(part1(polymer).into())
+    }
+    fn part2(polymer: (Polymer, char)) -> Result<Answer> {
+        Ok(part2(polymer).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(20)))
+    }
+}
@@ -0,0 +1,145 @@
+use crate::Vec2;
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type Co = Vec2;
+type Board = Grid<usize>;
+
+#[derive(Eq)]
+struct Node {
+    coords: Co,
+    priority: usize,
+}
+
+impl Node {
+    pub fn new((coords, priority): (Co, usize)) -> Self {
+        return Node { coords, priority };
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        return self.priority.cmp(&other.priority);
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        return self.priority == other.priority;
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Board> {
+    return Grid::parse_digits(&input);
+}
+
+fn enlarge_board(tile: &Board, macro_board_size: usize) -> Board {
+    let (width, height) = (tile.width(), tile.height());
+    return Grid::from_fn(width * macro_board_size, height * macro_board_size, |pos| {
+        let (x, y) = (pos.x() as usize, pos.y() as usize);
+        let (x_offset, y_offset) = (x / width, y / height);
+        let d = tile[Vec2::new((x % width) as isize, (y % height) as isize)];
+        (d + x_offset + y_offset - 1) % 9 + 1
+    });
+}
+
+fn find_maximum_coord(board: &Board) -> Option<Co> {
+    return board.positions().last();
+}
+
+fn search_heuristic(a: Co, b: Co) -> usize {
+    return a.manhattan_distance(&b);
+}
+
+fn find_path(board: Board) -> Option<usize> {
+    let goal = find_maximum_coord(&board)?;
+    let mut came_from: HashMap<Co, Co> = HashMap::default();
+    let mut to_visit: BinaryHeap<Node> = BinaryHeap::default();
+    let mut cost_so_far: HashMap<Co, usize> = HashMap::default();
+    let mut visited_so_far: HashSet<Co> = HashSet::default();
+
+    let start = Vec2::default();
+    to_visit.push(Node::new((start, *board.get(start)?)));
+    cost_so_far.insert(start, 0);
+
+    while !to_visit.is_empty() {
+        let current = to_visit.pop()?;
+        // println!("Visiting {:?} towards {:?}", current.coords, goal);
+        // if current.coords == goal {
+        //     break;
+        // }
+
+        visited_so_far.insert(current.coords);
+
+        for next_coords in board.neighbors4(current.coords) {
+            let new_cost = cost_so_far[&(current.coords)] + board[next_coords];
+            let next_cost_so_far = cost_so_far.entry(next_coords).or_insert(usize::MAX);
+
+            // println!(
+            //     "Looking at {:?}, the current cost is {}, the next cost is {}",
+            //     next_coords, next_cost_so_far, new_cost
+            // );
+
+            if *next_cost_so_far > new_cost {
+                *next_cost_so_far = new_cost;
+                let next_priority =
+                    (9 - board[next_coords]) + search_heuristic(goal, next_coords);
+                to_visit.push(Node::new((next_coords, next_priority)));
+                came_from.insert(next_coords, current.coords);
+            }
+        }
+    }
+
+    return Some(cost_so_far[&goal]);
+}
+
+pub fn part1(board: Board) -> Result<usize> {
+    return find_path(board).ok_or(anyhow!("Path not found"));
+}
+pub fn part2(tile: Board) -> Result<usize> {
+    let board = enlarge_board(&tile, 5);
+
+    return find_path(board).ok_or(anyhow!("Path not found"));
+}
+
+/// A `size` by `size` cave of risk levels.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    return (0..size)
+        .map(|_| (0..size).map(|_| rng.range(1..=9).to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Board;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    fn parse(input: String) -> Result<Board> {
+        parse_input(input)
+    }
+    fn part1(board: Board) -> Result<Answer> {
+        Ok(part1(board)?.into())
+    }
+    fn part2(board: Board) -> Result<Answer> {
+        Ok(part2(board)?.into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(100)))
+    }
+}
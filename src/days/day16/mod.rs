@@ -0,0 +1,291 @@
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+
+#[derive(Debug)]
+pub enum Operation {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl TryFrom<usize> for Operation {
+    type Error = anyhow::Error;
+    fn try_from(n: usize) -> Result<Self> {
+        match n {
+            0 => Ok(Self::Sum),
+            1 => Ok(Self::Product),
+            2 => Ok(Self::Min),
+            3 => Ok(Self::Max),
+            5 => Ok(Self::Gt),
+            6 => Ok(Self::Lt),
+            7 => Ok(Self::Eq),
+            _ => Err(anyhow!("Unknown operation {}", n)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Packet {
+    Literal {
+        version: usize,
+        value: usize,
+    },
+    Operator {
+        op: Operation,
+        version: usize,
+        sub_packets: Vec<Packet>,
+    },
+}
+
+#[derive(Debug)]
+enum ParseContext {
+    Header,
+    ReadLiteral { version: usize },
+    ReadOperator { version: usize, op: usize },
+}
+
+fn convert_to_binary_from_hex(hex: &str) -> Result<String> {
+    hex.chars()
+        .map(|c| to_binary(c).ok_or(anyhow!("Invalid hex digit {:?}", c)))
+        .collect()
+}
+
+fn to_binary(c: char) -> Option<&'static str> {
+    match c {
+        '0' => Some("0000"),
+        '1' => Some("0001"),
+        '2' => Some("0010"),
+        '3' => Some("0011"),
+        '4' => Some("0100"),
+        '5' => Some("0101"),
+        '6' => Some("0110"),
+        '7' => Some("0111"),
+        '8' => Some("1000"),
+        '9' => Some("1001"),
+        'A' => Some("1010"),
+        'B' => Some("1011"),
+        'C' => Some("1100"),
+        'D' => Some("1101"),
+        'E' => Some("1110"),
+        'F' => Some("1111"),
+        _ => None,
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Packet> {
+    let mut binary_input = convert_to_binary_from_hex(&input)?;
+
+    parse_packet(&mut binary_input)
+}
+
+fn read_number_mut(string: &mut String, digits: usize) -> Result<usize> {
+    if string.len() < digits {
+        bail!("Packet ended while reading a {}-bit number", digits);
+    }
+    Ok(usize::from_str_radix(&string.drain(..digits).collect::<String>(), 2)?)
+}
+
+/// Checks the operator has as many sub-packets as it needs to be evaluated.
+fn operator(op: usize, version: usize, sub_packets: Vec<Packet>) -> Result<Packet> {
+    let op = Operation::try_from(op)?;
+    match op {
+        Operation::Min | Operation::Max if sub_packets.is_empty() => {
+            bail!("{:?} packet with no sub-packets", op)
+        }
+        Operation::Gt | Operation::Lt | Operation::Eq if sub_packets.len() != 2 => {
+            bail!("{:?} packet with {} sub-packets", op, sub_packets.len())
+        }
+        _ => Ok(Packet::Operator {
+            op,
+            version,
+            sub_packets,
+        }),
+    }
+}
+
+fn parse_packet(packet: &mut String) -> Result<Packet> {
+    let mut stack = vec![ParseContext::Header];
+
+    while let Some(current_context) = stack.pop() {
+        match current_context {
+            ParseContext::Header => {
+                let version = read_number_mut(packet, 3)?;
+                let packet_type = read_number_mut(packet, 3)?;
+                match packet_type {
+                    4 => stack.push(ParseContext::ReadLiteral { version }),
+                    op => stack.push(ParseContext::ReadOperator { version, op }),
+                }
+            }
+            ParseContext::ReadLiteral { version } => {
+                let mut should_continue = true;
+                let mut value = 0;
+                while should_continue {
+                    should_continue = read_number_mut(packet, 1)? == 1;
+                    value <<= 4;
+                    value += read_number_mut(packet, 4)?;
+                }
+
+                return Ok(Packet::Literal { version, value });
+            }
+            ParseContext::ReadOperator { version, op } => match read_number_mut(packet, 1)? {
+                0 => {
+                    let total_read_length = read_number_mut(packet, 15)?;
+                    if packet.len() < total_read_length {
+                        bail!("Packet ended before its {} bits of sub-packets", total_read_length);
+                    }
+                    let mut subpackets_string: String = packet.drain(..total_read_length).collect();
+                    let mut sub_packets: Vec<Packet> = Vec::default();
+                    while !subpackets_string.is_empty() {
+                        sub_packets.push(parse_packet(&mut subpackets_string)?);
+                    }
+                    return operator(op, version, sub_packets);
+                }
+                1 => {
+                    let total_subpackets_length = read_number_mut(packet, 11)?;
+                    let mut sub_packets: Vec<Packet> = Vec::default();
+                    for _ in 0..total_subpackets_length {
+                        sub_packets.push(parse_packet(packet)?);
+                    }
+
+                    return operator(op, version, sub_packets);
+                }
+                n => bail!("Unfamiliar length ID: {}", n),
+            },
+        }
+    }
+
+    unreachable!("Reached end of stack but did not return a packet");
+}
+
+fn count_versions(packet: &Packet) -> usize {
+    match packet {
+        Packet::Literal { version, .. } => *version,
+        Packet::Operator {
+            version,
+            sub_packets,
+            ..
+        } => *version + sub_packets.iter().map(count_versions).sum::<usize>(),
+    }
+}
+
+fn eval_packet(packet: &Packet) -> usize {
+    match packet {
+        Packet::Literal { value, .. } => *value,
+        Packet::Operator {
+            op, sub_packets, ..
+        } => match op {
+            Operation::Sum => sub_packets.iter().map(eval_packet).sum(),
+            Operation::Product => sub_packets.iter().map(eval_packet).product(),
+            Operation::Min => sub_packets.iter().map(eval_packet).min().unwrap(),
+            Operation::Max => sub_packets.iter().map(eval_packet).max().unwrap(),
+            Operation::Gt => {
+                let a = eval_packet(&sub_packets[0]);
+                let b = eval_packet(&sub_packets[1]);
+
+                if a > b { 1 } else { 0 }
+            }
+            Operation::Lt => {
+                let a = eval_packet(&sub_packets[0]);
+                let b = eval_packet(&sub_packets[1]);
+
+                if a < b { 1 } else { 0 }
+            }
+            Operation::Eq => {
+                let a = eval_packet(&sub_packets[0]);
+                let b = eval_packet(&sub_packets[1]);
+
+                if a == b { 1 } else { 0 }
+            }
+        },
+    }
+}
+
+pub fn part1(packet_prime: Packet) -> usize {
+    count_versions(&packet_prime)
+}
+
+pub fn part2(packet_prime: Packet) -> usize {
+    eval_packet(&packet_prime)
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: usize, width: usize) {
+    bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+}
+
+/// Products only take literals, and there are at most three of them, so that no
+/// value overflows.
+fn generate_packet(rng: &mut Rng, budget: &mut usize, depth: usize, literal: bool) -> Vec<bool> {
+    let mut bits = Vec::new();
+    push_bits(&mut bits, rng.below(8), 3);
+    *budget = budget.saturating_sub(1);
+
+    if literal || *budget == 0 || depth == 8 || rng.chance(1, 3) {
+        push_bits(&mut bits, 4, 3);
+        let value = rng.below(1 << 16);
+        let groups = (1..4).take_while(|i| value >> (4 * i) > 0).count() + 1;
+        for i in (0..groups).rev() {
+            bits.push(i > 0);
+            push_bits(&mut bits, value >> (4 * i) & 0xf, 4);
+        }
+        return bits;
+    }
+
+    let type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = if type_id > 4 { 2 } else { rng.range(1..=3) as usize };
+    let sub_packets = (0..count)
+        .flat_map(|_| generate_packet(rng, budget, depth + 1, type_id == 1))
+        .collect::<Vec<_>>();
+    push_bits(&mut bits, type_id, 3);
+    if sub_packets.len() < 1 << 15 && rng.chance(1, 2) {
+        bits.push(false);
+        push_bits(&mut bits, sub_packets.len(), 15);
+    } else {
+        bits.push(true);
+        push_bits(&mut bits, count, 11);
+    }
+    bits.extend(sub_packets);
+    return bits;
+}
+
+/// A transmission of about `size` packets.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut budget = size;
+    let mut bits = generate_packet(rng, &mut budget, 0, false);
+    while !bits.len().is_multiple_of(4) {
+        bits.push(false);
+    }
+    return bits
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |acc, &bit| acc << 1 | bit as u32);
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+
+    fn parse(input: String) -> Result<Packet> {
+        parse_input(input)
+    }
+    fn part1(packet: Packet) -> Result<Answer> {
+        Ok(part1(packet).into())
+    }
+    fn part2(packet: Packet) -> Result<Answer> {
+        Ok(part2(packet).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(50)))
+    }
+}
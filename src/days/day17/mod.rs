@@ -0,0 +1,138 @@
+use crate::Vec2;
+use crate::aabb::Rect;
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+struct Probe {
+  velocity: Vec2,
+  position: Vec2,
+}
+
+impl Probe {
+  pub fn new(initial_velocity: Vec2) -> Self {
+    Probe {
+      velocity: initial_velocity,
+      position: Vec2::default(),
+    }
+  }
+
+  pub fn tick(&self) -> Self {
+    let mut new_velocity = self.velocity + Vec2::new(-1, -1);
+    if new_velocity.x() < 0 {
+      new_velocity[0] = 0;
+    }
+    Probe {
+      position: self.position + self.velocity,
+      velocity: new_velocity,
+    }
+  }
+
+  pub fn missed(&self, zone: &LandingZone) -> bool {
+    self.position.x() > zone.0.max.x() || self.position.y() < zone.0.min.y()
+  }
+
+  pub fn landed(&self, zone: &LandingZone) -> bool {
+    zone.0.contains(&self.position)
+  }
+}
+
+pub struct LandingZone(Rect);
+
+impl FromStr for LandingZone {
+  type Err = anyhow::Error;
+  fn from_str(input: &str) -> Result<Self, <Self as FromStr>::Err> {
+    let p = |n: &str| n.parse().or(Err(anyhow!("Failed to parse number {:?}", n)));
+    let (_, ranges) = input.split_once(": ").ok_or(anyhow!("Split error"))?;
+    let (x_range, y_range) = ranges.split_once(", ").ok_or(anyhow!("Split error"))?;
+    let (min_x, max_x) = x_range
+      .strip_prefix("x=")
+      .and_then(|range| range.split_once(".."))
+      .ok_or(anyhow!("Failed to parse x range"))?;
+    let (min_y, max_y) = y_range
+      .strip_prefix("y=")
+      .and_then(|range| range.split_once(".."))
+      .ok_or(anyhow!("Failed to parse y range"))?;
+
+    Ok(Self(Rect::new(
+      Vec2::new(p(min_x)?, p(min_y)?),
+      Vec2::new(p(max_x)?, p(max_y)?),
+    )))
+  }
+}
+
+fn find_sum_of_consecutive(n: isize) -> isize {
+  (n * (n + 1)) / 2
+}
+
+fn find_all_viable_trajectories(zone: LandingZone) -> HashSet<Vec2> {
+  let max_vx = zone.0.max.x() + 1;
+  let max_vy = -zone.0.min.y();
+
+  let mut result = HashSet::default();
+
+  for vx in 0..=max_vx {
+    for vy in -max_vy..=max_vy {
+      let vel = Vec2::new(vx, vy);
+      let mut probe = Probe::new(vel);
+      loop {
+        probe = probe.tick();
+        if probe.landed(&zone) {
+          result.insert(vel);
+          break;
+        }
+        if probe.missed(&zone) {
+          break;
+        }
+      }
+    }
+  }
+
+  result
+}
+
+pub fn part1(landing_zone: LandingZone) -> usize {
+  let min_y = landing_zone.0.min.y();
+
+  find_sum_of_consecutive(-1 - min_y).try_into().unwrap()
+}
+
+pub fn part2(landing_zone: LandingZone) -> usize {
+  let initial_velocities = find_all_viable_trajectories(landing_zone);
+
+  initial_velocities.len()
+}
+
+/// A target area about `size` steps away, below the probe.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  let size = size.max(4) as isize;
+  let min_x = rng.range(size / 2..=size);
+  let max_x = min_x + rng.range(0..=size / 4);
+  let min_y = -rng.range(size / 2..=size);
+  let max_y = (min_y + rng.range(0..=size / 4)).min(-1);
+  return format!("target area: x={}..{}, y={}..{}", min_x, max_x, min_y, max_y);
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+  type Input = LandingZone;
+  const DAY: u8 = 17;
+  const TITLE: &'static str = "Trick Shot";
+
+  fn parse(input: String) -> Result<LandingZone> {
+    LandingZone::from_str(&input)
+  }
+  fn part1(landing_zone: LandingZone) -> Result<Answer> {
+    Ok(part1(landing_zone).into())
+  }
+  fn part2(landing_zone: LandingZone) -> Result<Answer> {
+    Ok(part2(landing_zone).into())
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(150)))
+  }
+}
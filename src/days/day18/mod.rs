@@ -1,10 +1,14 @@
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
 use std::iter::Peekable;
 use std::ops::Add;
 use std::str::Chars;
 use std::str::FromStr;
 
-struct Sfn(Vec<(usize, usize)>);
+pub struct Sfn(Vec<(usize, usize)>);
 impl Sfn {
   pub fn reduce(&mut self) {
     let sfn = &mut self.0;
@@ -56,7 +60,7 @@ impl Sfn {
   }
 }
 
-impl<'a> Add for &'a Sfn {
+impl Add for &Sfn {
   type Output = Sfn;
   fn add(self, rhs: Self) -> Sfn {
     let mut sfn_vec = Vec::new();
@@ -132,31 +136,29 @@ impl<'input> SfnParser<'input> {
   }
 }
 
-pub fn part1(input: String) -> usize {
-  let sfns = input
-    .lines()
+pub fn parse_input(input: String) -> Result<Vec<Sfn>> {
+  let sfns = input::lines(&input)
     .map(Sfn::from_str)
-    .collect::<Result<Vec<_>>>()
-    .expect("Parse error");
+    .collect::<Result<Vec<_>>>()?;
+  if sfns.is_empty() {
+    bail!("There are no snailfish numbers");
+  }
+  Ok(sfns)
+}
 
-  let final_sfn = sfns.into_iter().reduce(|a, b| (&a + &b)).unwrap();
+pub fn part1(sfns: Vec<Sfn>) -> usize {
+  let final_sfn = sfns.into_iter().reduce(|a, b| &a + &b ).unwrap();
 
   final_sfn.magnitude()
 }
 
-pub fn part2(input: String) -> usize {
-  let sfns = input
-    .lines()
-    .map(Sfn::from_str)
-    .collect::<Result<Vec<_>>>()
-    .expect("Parse error");
-
+pub fn part2(sfns: Vec<Sfn>) -> usize {
   sfns
     .iter()
     .map(|a| {
       sfns
         .iter()
-        .map(|b| (a.clone() + b.clone()).magnitude())
+        .map(|b| (a + b).magnitude())
         .max()
     })
     .max()
@@ -164,6 +166,48 @@ pub fn part2(input: String) -> usize {
     .unwrap()
 }
 
+fn generate_pair(rng: &mut Rng, depth: usize) -> String {
+  let element = |rng: &mut Rng| {
+    if depth < 4 && rng.chance(1, 2) {
+      generate_pair(rng, depth + 1)
+    } else {
+      rng.range(0..=9).to_string()
+    }
+  };
+  let left = element(rng);
+  let right = element(rng);
+  return format!("[{},{}]", left, right);
+}
+
+/// `size` snailfish numbers, already reduced.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  return (0..size.max(1))
+    .map(|_| generate_pair(rng, 1))
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+  type Input = Vec<Sfn>;
+  const DAY: u8 = 18;
+  const TITLE: &'static str = "Snailfish";
+
+  fn parse(input: String) -> Result<Vec<Sfn>> {
+    parse_input(input)
+  }
+  fn part1(sfns: Vec<Sfn>) -> Result<Answer> {
+    Ok(part1(sfns).into())
+  }
+  fn part2(sfns: Vec<Sfn>) -> Result<Answer> {
+    Ok(part2(sfns).into())
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(100)))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -0,0 +1,165 @@
+use anyhow::{anyhow, Result};
+use crate::{Rotation, Vec3, Vector};
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Solution, Status};
+use core::str::FromStr;
+use std::collections::HashSet;
+
+const HEADER_OFFSET: usize = 12; // length of "--- scanner "
+
+#[derive(Debug, Eq, Clone)]
+pub struct Scanner {
+    id: usize,
+    #[allow(dead_code)] // Work in progress
+    visible: Vec<Beacon>,
+    location: Option<Vec3>,
+}
+
+impl PartialEq for Scanner {
+    fn eq(&self, other: &Scanner) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Beacon {
+    /// Relative position
+    pos: Vec3,
+    neighbors: Vec<Vec3>,
+}
+
+impl FromStr for Scanner {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        let mut lines = input::lines(input);
+        let header = lines.next().ok_or(anyhow!("Missing header"))?;
+        let id = header
+            .get(HEADER_OFFSET..)
+            .ok_or(anyhow!("Invalid header {:?}", header))?
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>()
+            .parse()?;
+
+        let visible_locations = lines.map(|v| v.parse()).collect::<Result<Vec<Vec3>>>()?;
+
+        let visible = visible_locations
+            .clone()
+            .iter()
+            .map(|vec| {
+                let mut neighbors = visible_locations
+                    .iter()
+                    .filter(|oth| *oth != vec)
+                    .map(|other| other - vec)
+                    .collect::<Vec<_>>();
+
+                neighbors.sort_by_key(|neighbor| neighbor.square_distance(vec));
+
+                Beacon {
+                    pos: *vec,
+                    neighbors,
+                }
+            })
+            .collect();
+
+        let location = if id == 0 { Some(Vec3::default()) } else { None };
+
+        Ok(Scanner {
+            id,
+            visible,
+            location,
+        })
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Scanner>> {
+    input::blocks(&input).map(|block| block.parse()).collect()
+}
+
+#[allow(dead_code)] // Work in progress
+fn find_beacon_locations(scanners: Vec<Scanner>) -> HashSet<Vec3> {
+    let result = HashSet::new();
+
+    while scanners.iter().any(|s| s.location.is_none()) {
+        for scanner1 in scanners.iter() {
+            if scanner1.location.is_none() {
+                continue;
+            }
+            for scanner2 in scanners.iter() {
+                if scanner1 != scanner2 && scanner2.location.is_none() {
+                    todo!()
+                }
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part1(_scanners: Vec<Scanner>) -> usize {
+    0
+}
+pub fn part2(_scanners: Vec<Scanner>) -> usize {
+    0
+}
+
+/// `size` scanners, each placed near an earlier one so that their views overlap.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut scanners: Vec<[isize; 3]> = vec![[0, 0, 0]];
+    while scanners.len() < size {
+        let &[x, y, z] = rng.choose(&scanners);
+        let mut offset = || rng.range(-1200..=1200);
+        scanners.push([x + offset(), y + offset(), z + offset()]);
+    }
+    let mut beacons = Vec::new();
+    for &[x, y, z] in &scanners {
+        for _ in 0..rng.range(10..=20) {
+            let mut offset = || rng.range(-1000..=1000);
+            beacons.push([x + offset(), y + offset(), z + offset()]);
+        }
+    }
+
+    let rotations = Rotation::all().collect::<Vec<_>>();
+    return scanners
+        .iter()
+        .enumerate()
+        .map(|(id, scanner)| {
+            let rotation = *rng.choose(&rotations);
+            let mut report = vec![format!("--- scanner {} ---", id)];
+            for beacon in &beacons {
+                let relative = [0, 1, 2].map(|i| beacon[i] - scanner[i]);
+                if relative.iter().all(|d| d.abs() <= 1000) {
+                    let [x, y, z] = rotation.apply(Vector(relative)).0;
+                    report.push(format!("{},{},{}", x, y, z));
+                }
+            }
+            report.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+    // Work in progress, see `find_beacon_locations`
+    const STATUS: [Status; 2] = [Status::Stubbed; 2];
+
+    fn parse(input: String) -> Result<Vec<Scanner>> {
+        parse_input(input)
+    }
+    fn part1(scanners: Vec<Scanner>) -> Result<Answer> {
+        Ok(part1(scanners).into())
+    }
+    fn part2(scanners: Vec<Scanner>) -> Result<Answer> {
+        Ok(part2(scanners).into())
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(30)))
+    }
+}
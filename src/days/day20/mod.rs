@@ -0,0 +1,155 @@
+use anyhow::{anyhow, bail, Context, Result};
+use crate::Vec2;
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Part, Solution};
+use crate::visualize::{simulate, Frames};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Game {
+  bitmap: [u16; 512],
+  outside_value: u16,
+  image: Grid<u16>,
+}
+
+impl Game {
+  fn get_value(&self, pos: Vec2) -> usize {
+    let mut res = 0;
+    for dy in -1..=1 {
+      for dx in -1..=1 {
+        res <<= 1;
+        res += self.image.get(pos + Vec2::new(dx, dy)).unwrap_or(&self.outside_value)
+      }
+    }
+    res.into()
+  }
+
+  pub fn tick(&self) -> Self {
+    let next_outside_value = if self.outside_value == 1 {
+      self.bitmap[511]
+    } else {
+      self.bitmap[0]
+    };
+
+    // The image grows by a pixel on each side
+    let (width, height) = (self.image.width() + 2, self.image.height() + 2);
+    let new_image = Grid::from_fn(width, height, |pos| {
+      self.bitmap[self.get_value(pos - Vec2::new(1, 1))]
+    });
+
+    Self {
+      image: new_image,
+      outside_value: next_outside_value,
+      ..*self
+    }
+  }
+  pub fn count_lit(&self) -> usize {
+    self.image.values().filter(|&&v| v == 1).count()
+  }
+}
+
+impl FromStr for Game {
+  type Err = anyhow::Error;
+  fn from_str(input: &str) -> Result<Game> {
+    let (bitmap_str, image_str) = input::split_blocks_once(input)
+      .ok_or(anyhow!("First split failure"))?;
+    if bitmap_str.len() != 512 {
+      bail!("Expected 512 enhancement bits, found {}", bitmap_str.len());
+    }
+    let mut bitmap = [0; 512];
+    for (i, c) in bitmap_str.char_indices() {
+      if c == '#' {
+        bitmap[i] = 1;
+      }
+    }
+
+    let image = Grid::parse_with(image_str, |c| match c {
+      '#' => Ok(1),
+      '.' => Ok(0),
+      _ => Err(anyhow!("Unfamiliar character {}", c)),
+    })
+    .context("Invalid image")?;
+
+    Ok(Game {
+      bitmap,
+      image,
+      outside_value: 0,
+    })
+  }
+}
+
+impl Display for Game {
+  fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+    for row in self.image.rows() {
+      for &v in row {
+        write!(fmt, "{}", if v == 1 { '#' } else { '.' })?;
+      }
+      writeln!(fmt)?;
+    }
+    Ok(())
+  }
+}
+
+pub fn part1(game: Game) -> usize {
+  game.tick().tick().count_lit()
+}
+
+pub fn part2(game: Game) -> usize {
+  (0..50)
+    .fold(game, |acc, _| acc.tick())
+    .count_lit()
+}
+
+/// A `size` by `size` image. When the algorithm lights up dark pixels, it turns
+/// fully lit ones off, or the infinite image would stay lit.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  let pixel = |rng: &mut Rng| if rng.chance(1, 2) { '#' } else { '.' };
+  let mut algorithm = (0..512).map(|_| pixel(rng)).collect::<Vec<_>>();
+  if algorithm[0] == '#' {
+    algorithm[511] = '.';
+  }
+  let image = (0..size.max(1))
+    .map(|_| (0..size.max(1)).map(|_| pixel(rng)).collect::<String>())
+    .collect::<Vec<_>>()
+    .join("\n");
+  return format!("{}\n\n{}", algorithm.into_iter().collect::<String>(), image);
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+  type Input = Game;
+  const DAY: u8 = 20;
+  const TITLE: &'static str = "Trench Map";
+
+  fn parse(input: String) -> Result<Game> {
+    input.parse()
+  }
+  fn part1(game: Game) -> Result<Answer> {
+    Ok(part1(game).into())
+  }
+  fn part2(game: Game) -> Result<Answer> {
+    Ok(part2(game).into())
+  }
+  fn visualize(game: Game, part: Part) -> Result<Option<Frames>> {
+    let mut ticks_left = match part {
+      Part::One => 2,
+      Part::Two => 50,
+    };
+    let step = move |game: &mut Game| {
+      if ticks_left == 0 {
+        return false;
+      }
+      ticks_left -= 1;
+      *game = game.tick();
+      true
+    };
+    Ok(Some(simulate(game, step, Game::to_string)))
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(100)))
+  }
+}
@@ -1,7 +1,12 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-struct Dirac {
+pub struct Dirac {
   // (pos, score)
   p1: (usize, usize),
   p2: (usize, usize),
@@ -95,17 +100,17 @@ impl Dirac {
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-struct QuantumWinState {
+pub struct QuantumWinState {
   p1_wins: usize,
   p2_wins: usize,
 }
 
-trait Die {
+pub trait Die {
   fn roll(&mut self) -> usize;
   fn count_rolls(&self) -> usize;
 }
 
-trait QuantumDie {
+pub trait QuantumDie {
   fn roll(&self) -> &[usize];
 }
 
@@ -135,19 +140,31 @@ impl QuantumDie for QuantumDiracDie {
   }
 }
 
-fn parse_input(input: String) -> Option<Dirac> {
-  let (p1_str, p2_str) = input.split_once('\n')?;
-  let p1_1idx: usize = p1_str.chars().last()?.to_digit(10)?.try_into().ok()?;
-  let p2_1idx: usize = p2_str.chars().last()?.to_digit(10)?.try_into().ok()?;
+fn parse_starting_position(line: &str) -> Result<usize> {
+  let (_, position) = line
+    .rsplit_once(": ")
+    .ok_or(anyhow!("Invalid player {:?}", line))?;
+  let position: usize = position.parse()?;
+  if !(1..=10).contains(&position) {
+    bail!("Starting position {} is off the board", position);
+  }
+
+  Ok(position - 1)
+}
+
+pub fn parse_input(input: String) -> Result<Dirac> {
+  let (p1_str, p2_str) = match input::lines(&input).collect::<Vec<_>>()[..] {
+    [p1_str, p2_str] => (p1_str, p2_str),
+    _ => bail!("Expected a line for each player"),
+  };
 
-  Some(Dirac {
-    p1: (p1_1idx - 1, 0),
-    p2: (p2_1idx - 1, 0),
+  Ok(Dirac {
+    p1: (parse_starting_position(p1_str)?, 0),
+    p2: (parse_starting_position(p2_str)?, 0),
   })
 }
 
-pub fn part1(input: String) -> usize {
-  let mut game = parse_input(input).unwrap();
+pub fn part1(mut game: Dirac) -> usize {
   let mut die = DeterministicDie::new();
 
   let loser_score = game.play_till(1000, &mut die);
@@ -155,10 +172,37 @@ pub fn part1(input: String) -> usize {
   loser_score * die.count_rolls()
 }
 
-pub fn part2(input: String) -> usize {
-  let game = parse_input(input).unwrap();
+pub fn part2(game: Dirac) -> usize {
   let die = QuantumDiracDie;
 
   let res = game.quantum_play_till(21, &die);
   usize::max(res.p1_wins, res.p2_wins)
 }
+
+pub fn generate_input(rng: &mut Rng) -> String {
+  return (1..=2)
+    .map(|player| format!("Player {} starting position: {}", player, rng.range(1..=10)))
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+  type Input = Dirac;
+  const DAY: u8 = 21;
+  const TITLE: &'static str = "Dirac Dice";
+
+  fn parse(input: String) -> Result<Dirac> {
+    parse_input(input)
+  }
+  fn part1(game: Dirac) -> Result<Answer> {
+    Ok(part1(game).into())
+  }
+  fn part2(game: Dirac) -> Result<Answer> {
+    Ok(part2(game).into())
+  }
+  fn generate(rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng))
+  }
+}
@@ -0,0 +1,152 @@
+use anyhow::{anyhow, bail, Result};
+use std::str::FromStr;
+use crate::Vec3;
+use crate::aabb::Cuboid;
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+pub struct Instruction {
+  cuboid: Cuboid,
+  on: bool,
+}
+
+impl FromStr for Instruction {
+  type Err = anyhow::Error;
+  fn from_str(input: &str) -> Result<Self> {
+    fn parse_range(range: &str) -> Result<(isize, isize)> {
+      let (min, max) = range
+        .split_once("..")
+        .ok_or(anyhow!(".. split error"))?;
+
+      Ok((
+        min.parse()?,
+        max.parse()?,
+      ))
+    }
+    let (state, ranges) = input.split_once(' ').ok_or(anyhow!("First split error"))?;
+    let on = match state {
+      "on" => true,
+      "off" => false,
+      _ => bail!("Unknown state {:?}", state),
+    };
+    let [x_range, y_range, z_range] = ranges.split(',').collect::<Vec<_>>()[..] else {
+      bail!("Expected 3 ranges in {:?}", ranges);
+    };
+    let axis_range = |range: &str, prefix: &str| {
+      range
+        .strip_prefix(prefix)
+        .ok_or(anyhow!("Expected {:?} to start with {}", range, prefix))
+        .and_then(parse_range)
+    };
+    let (min_x, max_x) = axis_range(x_range, "x=")?;
+    let (min_y, max_y) = axis_range(y_range, "y=")?;
+    let (min_z, max_z) = axis_range(z_range, "z=")?;
+
+    let min = Vec3::new(min_x, min_y, min_z);
+    let max = Vec3::new(max_x, max_y, max_z);
+
+    Ok(Instruction {
+      cuboid: Cuboid::new(min, max),
+      on,
+    })
+  }
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Instruction>> {
+  input::lines(&input).map(|l| l.parse()).collect()
+}
+
+fn get_total_lit(instructions: Vec<Instruction>) -> usize {
+  let mut no_partial_overlap_instructions: Vec<Instruction> = Vec::new();
+
+  for Instruction { cuboid, on } in instructions.iter() {
+    let mut add = Vec::new();
+    if *on {
+      add.push(Instruction {
+        cuboid: *cuboid,
+        on: true,
+      })
+    }
+    for other_inst in no_partial_overlap_instructions.iter() {
+      if let Some(ci) = cuboid.intersection(&other_inst.cuboid) {
+        add.push(Instruction {
+          cuboid: ci,
+          on: !other_inst.on,
+        });
+      }
+    }
+    no_partial_overlap_instructions.extend(add);
+  }
+
+  no_partial_overlap_instructions
+    .into_iter()
+    .map(|Instruction { cuboid, on }| {
+      let sign = if on { 1 } else { -1 };
+      cuboid.volume() as isize * sign
+    })
+    .sum::<isize>()
+    .try_into()
+    .unwrap()
+}
+
+pub fn part1(instructions: Vec<Instruction>) -> usize {
+  let min_from = Vec3::new(-50, -50, -50);
+  let max_to = Vec3::new(50, 50, 50);
+  let init_area = Cuboid::new(min_from, max_to);
+
+  let instructions_in_area = instructions
+    .into_iter()
+    .filter(|inst| inst.cuboid.intersects(&init_area))
+    .collect();
+
+  get_total_lit(instructions_in_area)
+}
+
+pub fn part2(instructions: Vec<Instruction>) -> usize {
+  get_total_lit(instructions)
+}
+
+fn random_range(rng: &mut Rng, bound: isize, extent: isize) -> String {
+  let min = rng.range(-bound..=bound);
+  let max = (min + rng.range(0..=extent)).min(bound);
+  return format!("{}..{}", min, max);
+}
+
+/// `size` reboot steps. The first 20 are in the initialization region, like in
+/// the real inputs.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+  return (0..size)
+    .map(|i| {
+      let (bound, extent) = if i < 20 { (50, 50) } else { (100_000, 30_000) };
+      let state = if i == 0 || rng.chance(1, 2) { "on" } else { "off" };
+      let x = random_range(rng, bound, extent);
+      let y = random_range(rng, bound, extent);
+      let z = random_range(rng, bound, extent);
+      format!("{} x={},y={},z={}", state, x, y, z)
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+  type Input = Vec<Instruction>;
+  const DAY: u8 = 22;
+  const TITLE: &'static str = "Reactor Reboot";
+
+  fn parse(input: String) -> Result<Vec<Instruction>> {
+    parse_input(input)
+  }
+  fn part1(instructions: Vec<Instruction>) -> Result<Answer> {
+    Ok(part1(instructions).into())
+  }
+  fn part2(instructions: Vec<Instruction>) -> Result<Answer> {
+    Ok(part2(instructions).into())
+  }
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    Some(generate_input(rng, size.unwrap_or(420)))
+  }
+}
@@ -1,5 +1,12 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Part, Solution};
+use crate::visualize::Frames;
+use anyhow::{anyhow, bail, Result};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -118,7 +125,7 @@ impl Connections {
     }
 
     fn int_move(&self, from_tile: usize, to_tile: usize, target_is_tile: bool) -> Cost {
-        if target_is_tile && to_tile >= 2 && to_tile <= self.0.len() - 2 && to_tile % 2 == 0 {
+        if target_is_tile && to_tile >= 2 && to_tile <= self.0.len() - 2 && to_tile.is_multiple_of(2) {
             // to_tile is not accessible (directly in front of a room)
             return 0;
         }
@@ -234,12 +241,12 @@ impl<const ROOM_SIZE: usize> Display for Cave<ROOM_SIZE> {
 }
 
 fn parse<const ROOM_SIZE: usize>(input: String) -> Option<Cave<ROOM_SIZE>> {
-    let mut field = input.lines().skip(1).map(|line| line.as_bytes());
+    let mut field = input::lines(&input).skip(1).map(|line| line.as_bytes());
 
     let mut connections = Connections::default();
     for (i, amp) in field
         .next()?
-        .into_iter()
+        .iter()
         .filter(|&x| (b'A'..=b'D').contains(x) || *x == b'.')
         .enumerate()
     {
@@ -249,13 +256,12 @@ fn parse<const ROOM_SIZE: usize>(input: String) -> Option<Cave<ROOM_SIZE>> {
     let room_lines: Vec<_> = field.take(ROOM_SIZE).collect();
 
     let rooms = (0..4)
-        .into_iter()
         .map(|i| {
             Some(Room {
                 id: Amphipod::from_index(i)?,
                 slots: room_lines
                     .iter()
-                    .map(|line| Amphipod::parse(line[3 + 2 * i]))
+                    .map(|line| line.get(3 + 2 * i).and_then(|&c| Amphipod::parse(c)))
                     .collect::<Vec<_>>()
                     .try_into()
                     .ok()?,
@@ -313,17 +319,134 @@ fn solve<const ROOM_SIZE: usize>(cave: Cave<ROOM_SIZE>) -> Option<Cost> {
     None
 }
 
-pub fn part1(input: String) -> usize {
-    let cave = parse::<2>(input).expect("Unable to parse cave");
+/// Like `solve`, but remembers how every state was reached so it can return the
+/// cheapest path itself, with the energy spent by each step of it.
+fn find_path<const ROOM_SIZE: usize>(
+    cave: Cave<ROOM_SIZE>,
+) -> Option<Vec<(Cost, Cave<ROOM_SIZE>)>> {
+    let mut to_visit = BinaryHeap::new();
+    let mut best_costs = HashMap::new();
+    let mut came_from = HashMap::new();
+    to_visit.push(Element::new(0, cave));
+    best_costs.insert(cave, 0);
+
+    while let Some(Element { cost, item }) = to_visit.pop() {
+        if item.is_done() {
+            let mut path = vec![(cost, item)];
+            while let Some(previous) = came_from.get(&path[path.len() - 1].1) {
+                path.push((best_costs[previous], *previous));
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if cost > best_costs[&item] {
+            continue;
+        }
+
+        for (move_cost, next) in item.generate_moves().unwrap_or_default() {
+            let next_cost = cost + move_cost;
+            if best_costs.get(&next).is_none_or(|&best| next_cost < best) {
+                best_costs.insert(next, next_cost);
+                came_from.insert(next, item);
+                to_visit.push(Element::new(next_cost, next));
+            }
+        }
+    }
 
-    solve(cave).expect("Unable to solve")
+    None
 }
-pub fn part2(input: String) -> usize {
-    let mut input_lines: Vec<_> = input.lines().collect();
+
+fn path_frames<const ROOM_SIZE: usize>(cave: Cave<ROOM_SIZE>) -> Result<Frames> {
+    let path = find_path(cave).ok_or(anyhow!("Unable to solve"))?;
+
+    Ok(Box::new(
+        path.into_iter()
+            .map(|(cost, cave)| format!("Energy: {}\n{}", cost, cave)),
+    ))
+}
+
+/// The second part unfolds the diagram, adding two lines to every room.
+fn unfold(input: &str) -> Result<String> {
+    let mut input_lines: Vec<_> = input::lines(input).collect();
+    // The new lines go below the first line of the rooms
+    if input_lines.len() < 3 {
+        bail!(
+            "Expected a diagram of at least 3 lines, found {}",
+            input_lines.len()
+        );
+    }
     input_lines.insert(3, "  #D#B#A#C#");
     input_lines.insert(3, "  #D#C#B#A#");
-    let new_input = input_lines.join("\n");
-    let cave = parse::<4>(new_input).expect("Unable to parse cave");
+    Ok(input_lines.join("\n"))
+}
+
+/// The diagram as given for the first part, and unfolded for the second.
+#[derive(Debug, Clone)]
+pub struct Burrow {
+    folded: Cave<2>,
+    unfolded: Cave<4>,
+}
 
-    solve(cave).expect("Unable to solve")
+pub fn parse_input(input: String) -> Result<Burrow> {
+    for kind in ['A', 'B', 'C', 'D'] {
+        let count = input.chars().filter(|&c| c == kind).count();
+        if count != 2 {
+            bail!("Expected 2 amphipods of type {}, found {}", kind, count);
+        }
+    }
+    let unfolded = parse::<4>(unfold(&input)?).ok_or(anyhow!("Unable to parse cave"))?;
+    let folded = parse::<2>(input).ok_or(anyhow!("Unable to parse cave"))?;
+
+    Ok(Burrow { folded, unfolded })
+}
+
+pub fn part1(burrow: Burrow) -> Result<usize> {
+    solve(burrow.folded).ok_or(anyhow!("Unable to solve"))
+}
+pub fn part2(burrow: Burrow) -> Result<usize> {
+    solve(burrow.unfolded).ok_or(anyhow!("Unable to solve"))
+}
+
+pub fn generate_input(rng: &mut Rng) -> String {
+    let mut amphipods = *b"AABBCCDD";
+    rng.shuffle(&mut amphipods);
+    let row = |row: &[u8]| {
+        row.iter()
+            .map(|&amphipod| (amphipod as char).to_string())
+            .collect::<Vec<_>>()
+            .join("#")
+    };
+    return format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########",
+        row(&amphipods[..4]),
+        row(&amphipods[4..])
+    );
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Amphipod";
+
+    fn parse(input: String) -> Result<Burrow> {
+        parse_input(input)
+    }
+    fn part1(burrow: Burrow) -> Result<Answer> {
+        Ok(part1(burrow)?.into())
+    }
+    fn part2(burrow: Burrow) -> Result<Answer> {
+        Ok(part2(burrow)?.into())
+    }
+    fn visualize(burrow: Burrow, part: Part) -> Result<Option<Frames>> {
+        let frames = match part {
+            Part::One => path_frames(burrow.folded),
+            Part::Two => path_frames(burrow.unfolded),
+        };
+        Ok(Some(frames?))
+    }
+    fn generate(rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng))
+    }
 }
@@ -0,0 +1,66 @@
+// Solved using pen and paper <3
+
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::solution::{Solution, Status};
+use anyhow::Result;
+
+pub fn part1() -> usize {
+    99999795919456
+}
+
+pub fn part2() -> usize {
+    45311191516111
+}
+
+/// A MONAD program that accepts some model numbers, made of 14 blocks like the real
+/// inputs. `z` is a stack of base 26 digits: seven blocks push their digit onto it,
+/// and the other seven pop one, leaving it popped only when their digit is off from
+/// the pushed one by the right amount.
+pub fn generate_input(rng: &mut Rng) -> String {
+    let mut pushed_offsets = Vec::new();
+    let mut pushes_left = 7;
+    let mut blocks = Vec::new();
+    for _ in 0..14 {
+        // Adding 10 or more to a digit never matches, so `x` always pushes
+        let (div, add_x, add_y) =
+            if pushes_left > 0 && (pushed_offsets.is_empty() || rng.chance(1, 2)) {
+                pushes_left -= 1;
+                let add_y = rng.range(1..=16);
+                pushed_offsets.push(add_y);
+                (1, rng.range(10..=16), add_y)
+            } else {
+                let pushed_offset = pushed_offsets.pop().unwrap();
+                (26, rng.range(-8..=8) - pushed_offset, rng.range(1..=16))
+            };
+        blocks.push(format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y",
+            div, add_x, add_y
+        ));
+    }
+    return blocks.join("\n");
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = ();
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+    const STATUS: [Status; 2] = [Status::Hardcoded; 2];
+
+    fn parse(_: String) -> Result<()> {
+        Ok(())
+    }
+    fn part1(_: ()) -> Result<Answer> {
+        Ok(part1().into())
+    }
+    fn part2(_: ()) -> Result<Answer> {
+        Ok(part2().into())
+    }
+    fn generate(rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng))
+    }
+}
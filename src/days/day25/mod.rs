@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+use crate::Vec2;
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::{Part, Solution, Status};
+use crate::visualize::{simulate, Frames};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum Herd {
+    S,
+    E,
+}
+
+impl Herd {
+    fn direction(&self) -> Vec2 {
+        match self {
+            Self::S => Vec2::new(0, 1),
+            Self::E => Vec2::new(1, 0),
+        }
+    }
+}
+
+fn char_to_tile(c: char) -> Option<Herd> {
+    match c {
+        'v' => Some(Herd::S),
+        '>' => Some(Herd::E),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Field(Grid<Option<Herd>>);
+
+impl FromStr for Field {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        let grid = Grid::parse_with(input, |c| match c {
+            '.' => Ok(None),
+            c => Ok(Some(char_to_tile(c).ok_or(anyhow!("Unfamiliar character {:?}", c))?)),
+        })?;
+
+        // Herds leaving one edge come back at the opposite one
+        Ok(Self(grid.wrapping()))
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        for row in self.0.rows() {
+            for tile in row {
+                let c = match tile {
+                    None => ".",
+                    Some(Herd::S) => "v",
+                    Some(Herd::E) => ">",
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Field {
+    fn new() -> Self {
+        Self(Grid::default())
+    }
+    fn step(&self) -> Self {
+        self.move_herd(Herd::E).move_herd(Herd::S)
+    }
+
+    fn move_herd(&self, herd: Herd) -> Self {
+        let will_move = self.0.iter().filter_map(|(pos, value)| {
+            if *value != Some(herd.clone()) {
+                return None;
+            }
+            let next_position = self.0.wrap(pos + herd.direction())?;
+            if self.0[next_position].is_some() {
+                return None;
+            }
+            Some((pos, next_position))
+        });
+
+        let mut next_field = self.clone();
+
+        for (pos, next_position) in will_move {
+            next_field.0[next_position] = Some(herd.clone());
+            next_field.0[pos] = None;
+        }
+
+        next_field
+    }
+}
+
+pub fn part1(mut field: Field) -> usize {
+    let mut prev_field = Field::new();
+    let mut steps = 0;
+
+    while field != prev_field {
+        steps += 1;
+        prev_field = field.clone();
+        field = field.step();
+    }
+
+    steps
+}
+
+/// A `size` by `size` seafloor. Unlike the real inputs, a random one isn't
+/// guaranteed to ever stop moving.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    return (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *rng.choose(&['.', '.', '>', 'v']))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Field;
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+    // There is no second puzzle on the last day
+    const STATUS: [Status; 2] = [Status::Implemented, Status::Stubbed];
+
+    fn parse(input: String) -> Result<Field> {
+        input.parse()
+    }
+    fn part1(field: Field) -> Result<Answer> {
+        Ok(part1(field).into())
+    }
+    fn part2(_: Field) -> Result<Answer> {
+        // The last day only has one puzzle
+        Ok(Answer::None)
+    }
+    fn visualize(field: Field, part: Part) -> Result<Option<Frames>> {
+        if part == Part::Two {
+            return Ok(None);
+        }
+        let step = |field: &mut Field| {
+            let next = field.step();
+            if next == *field {
+                return false;
+            }
+            *field = next;
+            true
+        };
+        Ok(Some(simulate(field, step, Field::to_string)))
+    }
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_input(rng, size.unwrap_or(137)))
+    }
+}
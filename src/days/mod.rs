@@ -0,0 +1,52 @@
+#![allow(clippy::needless_return)]
+
+use crate::register_days;
+
+register_days! {
+    day01 => Day01,
+    day02 => Day02,
+    day03 => Day03,
+    day04 => Day04,
+    day05 => Day05,
+    day06 => Day06,
+    day07 => Day07,
+    day08 => Day08,
+    day09 => Day09,
+    day10 => Day10,
+    day11 => Day11,
+    day12 => Day12,
+    day13 => Day13,
+    day14 => Day14,
+    day15 => Day15,
+    day16 => Day16,
+    day17 => Day17,
+    day18 => Day18,
+    day19 => Day19,
+    day20 => Day20,
+    day21 => Day21,
+    day22 => Day22,
+    #[ignore = "the sample takes minutes to solve"]
+    day23 => Day23,
+    day24 => Day24,
+    day25 => Day25,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::registry;
+    use crate::rng::Rng;
+
+    #[test]
+    fn generated_inputs_parse() {
+        for solution in registry().iter() {
+            for seed in 0..5 {
+                if let Some(input) = solution.generate(&mut Rng::new(seed), None) {
+                    let day = solution.metadata().day;
+                    if let Err(err) = solution.parse(input) {
+                        panic!("Day {} with seed {}: {:?}", day, seed, err);
+                    }
+                }
+            }
+        }
+    }
+}
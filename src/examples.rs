@@ -0,0 +1,87 @@
+use crate::answers::{ExpectedAnswers, Verdict};
+use crate::runner::run_part;
+use crate::solution::{DynSolution, Part, Status};
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// A sample input from `<dir>/dayNN/<name>.txt`. Its expected answers are read from
+/// `<name>.toml` next to it, in the same format as `answers.toml`. Parts without
+/// an expected answer are not run, nor are stubbed parts.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: ExpectedAnswers,
+}
+
+/// Loads a day's examples, sorted by name. A day without a directory has none.
+pub fn load(dir: &Path, day: u8) -> Result<Vec<Example>> {
+    let day_dir = dir.join(format!("day{:02}", day));
+    if !day_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = std::fs::read_dir(&day_dir)
+        .with_context(|| format!("Failed to read {:?}", day_dir))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .map(|path| {
+            let input = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {:?}", path))?;
+            Ok(Example {
+                name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+                input,
+                expected: ExpectedAnswers::load(&path.with_extension("toml"))?,
+            })
+        })
+        .collect()
+}
+
+/// Runs a solution on each of its examples, describing every wrong answer.
+pub fn check(solution: &'static dyn DynSolution, dir: &Path) -> Result<Vec<String>> {
+    let day = solution.metadata().day;
+    let mut failures = Vec::new();
+
+    for example in load(dir, day)? {
+        if Part::ALL
+            .iter()
+            .all(|&part| example.expected.get(day, part).is_none())
+        {
+            bail!(
+                "Example {} has no expected answers for day {}",
+                example.name,
+                day
+            );
+        }
+        for part in Part::ALL {
+            if example.expected.get(day, part).is_none()
+                || solution.metadata().status(part) == Status::Stubbed
+            {
+                continue;
+            }
+            let run = run_part(solution, part, example.input.clone())
+                .with_context(|| format!("Example {}", example.name))?;
+            if let Some(failure) = run.failure {
+                failures.push(format!(
+                    "Day {} example {} part {}: {:?}",
+                    day, example.name, part, failure
+                ));
+                continue;
+            }
+            let answer = run.answer.to_string();
+            if let Verdict::Fail { expected } = example.expected.check(day, part, &answer) {
+                failures.push(format!(
+                    "Day {} example {} part {}: expected {:?}, got {:?}",
+                    day, example.name, part, expected, answer
+                ));
+            }
+        }
+    }
+
+    Ok(failures)
+}
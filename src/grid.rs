@@ -0,0 +1,252 @@
+use crate::input;
+use crate::Vec2;
+use anyhow::{anyhow, bail, Result};
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+const ORTHOGONAL: [Vec2; 4] = [
+    Vec2::new(0, -1),
+    Vec2::new(-1, 0),
+    Vec2::new(1, 0),
+    Vec2::new(0, 1),
+];
+
+const SURROUNDING: [Vec2; 8] = [
+    Vec2::new(-1, -1),
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(-1, 0),
+    Vec2::new(1, 0),
+    Vec2::new(-1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(1, 1),
+];
+
+/// A rectangle of cells stored row by row, indexed by `Vec2` positions with `(0, 0)`
+/// at the top left. Positions outside of it have no cell, unless it wraps around at
+/// the edges like a torus (see `wrapping`).
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrapping: bool,
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+            wrapping: false,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid with each cell set by `cell` from its position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Vec2) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Vec2::new(x as isize, y as isize)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+            wrapping: false,
+        }
+    }
+
+    /// Parses a normalized map with a character per cell. Every line must be as long as
+    /// the first.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut grid = Self::default();
+        for (y, line) in input::lines(input).enumerate() {
+            let row = line.chars().map(&mut cell).collect::<Result<Vec<_>>>()?;
+            if y == 0 {
+                grid.width = row.len();
+            } else if row.len() != grid.width {
+                bail!(
+                    "Line {} is {} cells wide, expected {}",
+                    y + 1,
+                    row.len(),
+                    grid.width
+                );
+            }
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        if grid.cells.is_empty() {
+            bail!("The grid is empty");
+        }
+        Ok(grid)
+    }
+
+    /// Makes positions past an edge continue from the opposite edge.
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The position of the cell at `pos`: `pos` itself when it is on the grid, where
+    /// it wraps around to on a wrapping grid, and `None` otherwise.
+    pub fn wrap(&self, pos: Vec2) -> Option<Vec2> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        if self.wrapping && !self.is_empty() {
+            return Some(Vec2::new(
+                pos.x().rem_euclid(width),
+                pos.y().rem_euclid(height),
+            ));
+        }
+        ((0..width).contains(&pos.x()) && (0..height).contains(&pos.y())).then_some(pos)
+    }
+
+    fn offset(&self, pos: Vec2) -> Option<usize> {
+        let pos = self.wrap(pos)?;
+        Some(pos.y() as usize * self.width + pos.x() as usize)
+    }
+
+    pub fn get(&self, pos: Vec2) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.cells[offset])
+    }
+    pub fn get_mut(&mut self, pos: Vec2) -> Option<&mut T> {
+        self.offset(pos).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2> {
+        let width = self.width;
+        (0..self.len()).map(move |i| Vec2::new((i % width) as isize, (i / width) as isize))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The positions above, left, right and below `pos` that have a cell.
+    pub fn neighbors4(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.neighbors(pos, &ORTHOGONAL)
+    }
+
+    /// The positions around `pos`, diagonals included, that have a cell.
+    pub fn neighbors8(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.neighbors(pos, &SURROUNDING)
+    }
+
+    fn neighbors(&self, pos: Vec2, offsets: &'static [Vec2]) -> impl Iterator<Item = Vec2> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.wrap(pos + offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            wrapping: self.wrapping,
+        }
+    }
+}
+
+impl Grid<usize> {
+    /// Parses a map of single digits.
+    pub fn parse_digits(input: &str) -> Result<Self> {
+        Self::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or(anyhow!("Not a digit: {:?}", c))
+        })
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        Self::parse_with(input, Ok)
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Vec2) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Vec2::new(2, 1)], 6);
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let values = |positions: Vec<Vec2>| positions.iter().map(|&p| grid[p]).collect::<Vec<_>>();
+        let corner = Vec2::new(0, 0);
+        assert_eq!(values(grid.neighbors4(corner).collect()), [2, 4]);
+        assert_eq!(values(grid.neighbors8(corner).collect()), [2, 4, 5]);
+
+        let torus = grid.clone().wrapping();
+        assert_eq!(torus[Vec2::new(-1, 2)], 3);
+        assert_eq!(values(torus.neighbors4(corner).collect()), [4, 3, 2, 4]);
+
+        assert!(Grid::parse_digits("12\n3").is_err());
+        assert!(Grid::parse_digits("1a").is_err());
+        assert!("".parse::<Grid<char>>().is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use crate::bench::PartBench;
+use crate::report::{Record, ToRecord, Value};
+use crate::solution::Part;
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::BTreeSet;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+use std::time::Duration;
+
+const HEADER: &str = "revision,timestamp,day,part,iterations,parse_median_ns,solve_median_ns";
+
+/// One benchmarked part, as kept in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub revision: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: Part,
+    pub iterations: usize,
+    pub parse_median: Duration,
+    pub solve_median: Duration,
+}
+
+impl Entry {
+    pub fn new(bench: &PartBench, revision: &str, timestamp: u64) -> Self {
+        Self {
+            revision: revision.to_string(),
+            timestamp,
+            day: bench.day,
+            part: bench.part,
+            iterations: bench.iterations,
+            parse_median: bench.parse.median,
+            solve_median: bench.solve.median,
+        }
+    }
+
+    /// The time revisions are compared on.
+    pub fn median(&self) -> Duration {
+        self.parse_median + self.solve_median
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.revision,
+            self.timestamp,
+            self.day,
+            self.part,
+            self.iterations,
+            self.parse_median.as_nanos(),
+            self.solve_median.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Entry {
+    type Err = anyhow::Error;
+    fn from_str(line: &str) -> Result<Self> {
+        let fields: Vec<_> = line.split(',').collect();
+        let [revision, timestamp, day, part, iterations, parse_median, solve_median] = fields[..]
+        else {
+            bail!("Expected 7 fields, found {}", fields.len());
+        };
+        let part = part.parse()?;
+        Ok(Self {
+            revision: revision.to_string(),
+            timestamp: timestamp.parse()?,
+            day: day.parse()?,
+            part: Part::from_number(part).ok_or(anyhow!("There is no part {}", part))?,
+            iterations: iterations.parse()?,
+            parse_median: Duration::from_nanos(parse_median.parse()?),
+            solve_median: Duration::from_nanos(solve_median.parse()?),
+        })
+    }
+}
+
+/// The git revision checked out, marked `-dirty` when tracked files have uncommitted
+/// changes, or `unknown` outside of a git checkout.
+pub fn current_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(revision) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if !changes.is_empty() => format!("{}-dirty", revision),
+            _ => revision,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Benchmark results of every revision, in a CSV file that is only ever appended to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl FromStr for History {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        let mut lines = input.lines().enumerate();
+        match lines.next() {
+            None => return Ok(Self::default()),
+            Some((_, header)) if header.trim_end() == HEADER => {}
+            Some((_, header)) => bail!("Unexpected header {:?}", header),
+        }
+        let entries = lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                line.trim_end()
+                    .parse()
+                    .with_context(|| format!("Line {}", i + 1))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { entries })
+    }
+}
+
+impl History {
+    /// A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .with_context(|| format!("Invalid history file {:?}", path)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Failed to read {:?}", path)),
+        }
+    }
+
+    /// Adds entries to the file, creating it when needed.
+    pub fn append(path: &Path, entries: &[Entry]) -> Result<()> {
+        let mut lines = String::new();
+        if !path.exists() {
+            lines.push_str(HEADER);
+            lines.push('\n');
+        }
+        for entry in entries {
+            lines.push_str(&entry.to_line());
+            lines.push('\n');
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(lines.as_bytes()))
+            .with_context(|| format!("Failed to write {:?}", path))
+    }
+
+    pub fn has_revision(&self, revision: &str) -> bool {
+        self.entries.iter().any(|entry| entry.revision == revision)
+    }
+
+    /// The revision benchmarked last, not counting `revision`.
+    pub fn previous_revision(&self, revision: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .map(|entry| entry.revision.as_str())
+            .find(|&other| other != revision)
+    }
+
+    /// The latest result of a part at a revision, when it was benchmarked more than once.
+    fn latest(&self, revision: &str, day: u8, part: Part) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.revision == revision && entry.day == day && entry.part == part)
+    }
+
+    /// Compares the parts benchmarked at either revision. A part regressed when its
+    /// median grew by more than `threshold` percent.
+    pub fn compare(&self, baseline: &str, current: &str, threshold: f64) -> Vec<Comparison> {
+        let parts: BTreeSet<_> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.revision == baseline || entry.revision == current)
+            .map(|entry| (entry.day, entry.part))
+            .collect();
+        parts
+            .into_iter()
+            .map(|(day, part)| {
+                let median = |revision| self.latest(revision, day, part).map(Entry::median);
+                let (baseline, current) = (median(baseline), median(current));
+                let regressed = match (baseline, current) {
+                    (Some(baseline), Some(current)) => {
+                        current.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold / 100.0)
+                    }
+                    _ => false,
+                };
+                Comparison {
+                    day,
+                    part,
+                    baseline,
+                    current,
+                    regressed,
+                }
+            })
+            .collect()
+    }
+}
+
+/// How the median time of a part changed from one revision to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: Part,
+    pub baseline: Option<Duration>,
+    pub current: Option<Duration>,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// The change in percent.
+    pub fn change(&self) -> Option<f64> {
+        match (self.baseline, self.current) {
+            (Some(baseline), Some(current)) if !baseline.is_zero() => {
+                Some((current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match (self.baseline, self.current) {
+            (None, _) => "new",
+            (_, None) => "missing",
+            _ if self.regressed => "regressed",
+            _ => "ok",
+        }
+    }
+}
+
+impl ToRecord for Comparison {
+    fn to_record(&self) -> Record {
+        let duration = |duration: Option<Duration>| duration.map_or(Value::Null, Value::from);
+        vec![
+            ("day", Value::Int(self.day.into())),
+            ("part", Value::Int(self.part.number().into())),
+            ("baseline_median_ns", duration(self.baseline)),
+            ("current_median_ns", duration(self.current)),
+            // Basis points, to stay an integer
+            (
+                "change_bp",
+                self.change().map_or(Value::Null, |change| {
+                    Value::Int((change * 100.0).round() as i128)
+                }),
+            ),
+            ("status", Value::Str(self.status().to_string())),
+        ]
+    }
+}
+
+pub fn print_comparison_table(comparisons: &[Comparison]) {
+    let row = |columns: String| println!("{}", columns.trim_end());
+    let duration =
+        |duration: Option<Duration>| duration.map_or("-".to_string(), |d| format!("{:?}", d));
+    row(format!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>8}  {}",
+        "Day", "Part", "Baseline", "Current", "Change", "Status"
+    ));
+    for comparison in comparisons {
+        let status = match comparison.status() {
+            "regressed" => "REGRESSED",
+            "ok" => "",
+            status => status,
+        };
+        row(format!(
+            "{:>3}  {:>4}  {:>12}  {:>12}  {:>8}  {}",
+            comparison.day,
+            comparison.part,
+            duration(comparison.baseline),
+            duration(comparison.current),
+            comparison
+                .change()
+                .map_or("-".to_string(), |change| format!("{:+.1}%", change)),
+            status,
+        ));
+    }
+    let regressions = comparisons.iter().filter(|c| c.regressed).count();
+    row(format!(
+        "{} part{} compared, {} regressed",
+        comparisons.len(),
+        if comparisons.len() == 1 { "" } else { "s" },
+        regressions
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(revision: &str, day: u8, part: Part, millis: u64) -> Entry {
+        Entry {
+            revision: revision.to_string(),
+            timestamp: 1_640_000_000,
+            day,
+            part,
+            iterations: 10,
+            parse_median: Duration::from_millis(1),
+            solve_median: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let history = History {
+            entries: vec![
+                entry("abc123", 15, Part::One, 20),
+                entry("abc123-dirty", 23, Part::Two, 300),
+            ],
+        };
+        let lines: Vec<_> = history.entries.iter().map(Entry::to_line).collect();
+        let file = format!("{}\n{}\n", HEADER, lines.join("\n"));
+        assert_eq!(file.parse::<History>().unwrap(), history);
+        assert!("revision\nabc".parse::<History>().is_err());
+    }
+
+    #[test]
+    fn regressions() {
+        let history = History {
+            entries: vec![
+                entry("old", 15, Part::One, 99),
+                entry("old", 15, Part::Two, 99),
+                entry("old", 22, Part::One, 9),
+                entry("new", 15, Part::One, 109),
+                entry("new", 15, Part::Two, 111),
+                entry("new", 23, Part::One, 9),
+            ],
+        };
+        assert_eq!(history.previous_revision("new"), Some("old"));
+
+        let statuses: Vec<_> = history
+            .compare("old", "new", 10.0)
+            .iter()
+            .map(|c| (c.day, c.part.number(), c.status()))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                (15, 1, "ok"),
+                (15, 2, "regressed"),
+                (22, 1, "missing"),
+                (23, 1, "new")
+            ]
+        );
+    }
+}
@@ -0,0 +1,42 @@
+/// Converts CRLF line endings to LF and drops a leading byte order mark and any
+/// trailing newlines, so an editor-saved file reads the same as a downloaded one.
+/// Every input is normalized before it reaches a day's parser, so the splitting
+/// helpers below only have to deal with `\n`.
+pub fn normalize(input: &str) -> String {
+    input
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// The lines of a normalized input.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.split('\n')
+}
+
+/// The blocks of a normalized input, which are separated by blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n")
+}
+
+/// Splits a normalized input at its first blank line, for inputs made of a header
+/// block followed by a body.
+pub fn split_blocks_once(input: &str) -> Option<(&str, &str)> {
+    input.split_once("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        let input = normalize("\u{feff}a\r\nb\r\n\r\nc\r\n\r\n");
+        assert_eq!(input, "a\nb\n\nc");
+        assert_eq!(lines(&input).collect::<Vec<_>>(), ["a", "b", "", "c"]);
+        assert_eq!(blocks(&input).collect::<Vec<_>>(), ["a\nb", "c"]);
+        assert_eq!(split_blocks_once(&input), Some(("a\nb", "c")));
+        assert_eq!(normalize("1,2,3\n"), "1,2,3");
+    }
+}
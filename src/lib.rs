@@ -1,184 +1,399 @@
+pub mod aabb;
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+/// Each `dayNN` module has `part1` and `part2` functions over a typed input, which
+/// is built by a `parse_input` function or a `FromStr` impl. Both expect input
+/// normalized by `input::normalize`. A unit struct implements `Solution` on top.
+pub mod days;
+pub mod examples;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod line;
+pub mod ocr;
+pub mod report;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod visualize;
+
 use anyhow::{anyhow, Result};
-use std::ops::{Add, Mul, Neg, Sub};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub struct Vec3(pub isize, pub isize, pub isize);
+/// A point or offset on an integer grid with `N` axes.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Vector<const N: usize>(pub [isize; N]);
+
+pub type Vec2 = Vector<2>;
+pub type Vec3 = Vector<3>;
+
+impl Vec2 {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self([x, y])
+    }
+
+    pub fn x(&self) -> isize {
+        self.0[0]
+    }
+    pub fn y(&self) -> isize {
+        self.0[1]
+    }
+}
 
 impl Vec3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self([x, y, z])
+    }
+
+    pub fn x(&self) -> isize {
+        self.0[0]
+    }
+    pub fn y(&self) -> isize {
+        self.0[1]
+    }
+    pub fn z(&self) -> isize {
+        self.0[2]
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    /// Applies `f` to each component.
+    pub fn map(self, f: impl Fn(isize) -> isize) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// Combines the components of both vectors pairwise.
+    pub fn zip_map(self, other: Self, f: impl Fn(isize, isize) -> isize) -> Self {
+        let mut result = self;
+        for (a, b) in result.0.iter_mut().zip(other.0) {
+            *a = f(*a, b);
+        }
+        result
+    }
+
     pub fn min(a: &Self, b: &Self) -> Self {
-        Self(
-            isize::min(a.0, b.0),
-            isize::min(a.1, b.1),
-            isize::min(a.2, b.2),
-        )
+        a.zip_map(*b, isize::min)
     }
     pub fn max(a: &Self, b: &Self) -> Self {
-        Self(
-            isize::max(a.0, b.0),
-            isize::max(a.1, b.1),
-            isize::max(a.2, b.2),
-        )
+        a.zip_map(*b, isize::max)
     }
 
+    pub fn signum(self) -> Self {
+        self.map(isize::signum)
+    }
+
+    pub fn dot(&self, other: &Self) -> isize {
+        self.0.iter().zip(other.0).map(|(a, b)| a * b).sum()
+    }
+
+    /// The distance to `other` in `metric`, or `None` if it overflows a `usize`.
+    pub fn checked_distance(&self, other: &Self, metric: Metric) -> Option<usize> {
+        let mut offsets = self.0.iter().zip(other.0).map(|(a, b)| a.abs_diff(b));
+        match metric {
+            Metric::Manhattan => offsets.try_fold(0usize, |sum, d| sum.checked_add(d)),
+            Metric::Chebyshev => Some(offsets.max().unwrap_or(0)),
+            Metric::SquareEuclidean => {
+                offsets.try_fold(0usize, |sum, d| sum.checked_add(d.checked_mul(d)?))
+            }
+        }
+    }
+
+    /// The distance to `other` in `metric`. Panics if it overflows a `usize`, see
+    /// `checked_distance`.
+    pub fn distance(&self, other: &Self, metric: Metric) -> usize {
+        self.checked_distance(other, metric)
+            .unwrap_or_else(|| panic!("The distance from {:?} to {:?} overflows", self, other))
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.distance(other, Metric::Manhattan)
+    }
+    pub fn chebyshev_distance(&self, other: &Self) -> usize {
+        self.distance(other, Metric::Chebyshev)
+    }
     pub fn square_distance(&self, other: &Self) -> usize {
-        ((self.0 - other.0).pow(2) + (self.1 - other.1).pow(2) + (self.2 - other.2).pow(2))
-            .try_into()
-            .unwrap()
+        self.distance(other, Metric::SquareEuclidean)
     }
 }
 
-impl FromStr for Vec3 {
+/// How `Vector::distance` measures the distance between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// The sum of the distances along each axis, or taxicab distance.
+    Manhattan,
+    /// The largest distance along any axis, or how many king moves apart the points are.
+    Chebyshev,
+    /// The square of the straight-line distance, which stays an integer.
+    SquareEuclidean,
+}
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> FromStr for Vector<N> {
     type Err = anyhow::Error;
     fn from_str(input: &str) -> Result<Self> {
         let mut parts = input.split(',');
-        let x = parts.next().ok_or(anyhow!("No x"))?.parse::<isize>()?;
-        let y = parts.next().ok_or(anyhow!("No y"))?.parse::<isize>()?;
-        let z = parts.next().ok_or(anyhow!("No z"))?.parse::<isize>()?;
+        let mut vector = Self::default();
+        for (axis, component) in vector.0.iter_mut().enumerate() {
+            let part = parts
+                .next()
+                .ok_or(anyhow!("No component {} in {:?}", axis, input))?;
+            *component = part.parse()?;
+        }
+        if parts.next().is_some() {
+            return Err(anyhow!("More than {} components in {:?}", N, input));
+        }
 
-        Ok(Self(x, y, z))
+        Ok(vector)
     }
 }
 
-impl Add for Vec3 {
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = isize;
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut isize {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> AddAssign for Vector<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = self.zip_map(other, |a, b| a + b);
+    }
+}
+
+impl<const N: usize> SubAssign for Vector<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.zip_map(other, |a, b| a - b);
+    }
+}
+
+impl<const N: usize> Add for Vector<N> {
     type Output = Self;
-    fn add(self, other: Self) -> <Self as Add<Self>>::Output {
-        Self(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
     }
 }
 
-impl Mul<isize> for Vec3 {
+impl<const N: usize> Mul<isize> for Vector<N> {
     type Output = Self;
-    fn mul(self, scalar: isize) -> <Self as Mul<isize>>::Output {
-        Self(self.0 * scalar, self.1 * scalar, self.2 * scalar)
+    fn mul(self, scalar: isize) -> Self {
+        self.map(|a| a * scalar)
     }
 }
 
-impl Neg for Vec3 {
+impl<const N: usize> Neg for Vector<N> {
     type Output = Self;
-    fn neg(self) -> <Self as Neg>::Output {
+    fn neg(self) -> Self {
         self * -1
     }
 }
 
-impl Sub for Vec3 {
+impl<const N: usize> Sub for Vector<N> {
     type Output = Self;
-    fn sub(self, other: Self) -> <Self as Sub<Self>>::Output {
-        self + (-other)
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
     }
 }
 
-impl<'a, 'b> Add<&'b Vec3> for &'a Vec3 {
-    type Output = Vec3;
-    fn add(self, other: &'b Vec3) -> Vec3 {
-        Vec3(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+impl<'b, const N: usize> Add<&'b Vector<N>> for &Vector<N> {
+    type Output = Vector<N>;
+    fn add(self, other: &'b Vector<N>) -> Vector<N> {
+        *self + *other
     }
 }
 
-impl<'a, 'b> Mul<isize> for &'a Vec3 {
-    type Output = Vec3;
-    fn mul(self, scalar: isize) -> Vec3 {
-        Vec3(self.0 * scalar, self.1 * scalar, self.2 * scalar)
+impl<const N: usize> Mul<isize> for &Vector<N> {
+    type Output = Vector<N>;
+    fn mul(self, scalar: isize) -> Vector<N> {
+        *self * scalar
     }
 }
 
-impl<'a, 'b> Neg for &'a Vec3 {
-    type Output = Vec3;
-    fn neg(self) -> Vec3 {
-        self * -1
+impl<const N: usize> Neg for &Vector<N> {
+    type Output = Vector<N>;
+    fn neg(self) -> Vector<N> {
+        -*self
     }
 }
 
-impl<'a, 'b> Sub<&'b Vec3> for &'a Vec3 {
-    type Output = Vec3;
-    fn sub(self, other: &'b Vec3) -> Vec3 {
-        self + &(-other)
+impl<'b, const N: usize> Sub<&'b Vector<N>> for &Vector<N> {
+    type Output = Vector<N>;
+    fn sub(self, other: &'b Vector<N>) -> Vector<N> {
+        *self - *other
     }
 }
 
-// Vec2
+/// One of the 24 ways to turn a `Vec3` around the origin in steps of a quarter turn:
+/// a permutation of the axes, each of which may be flipped, that doesn't mirror.
+/// Axis `i` of a rotated vector is axis `axes[i]` of the original times `signs[i]`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [isize; 3],
+}
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub struct Vec2(pub isize, pub isize);
+impl Rotation {
+    pub const IDENTITY: Self = Self {
+        axes: [0, 1, 2],
+        signs: [1, 1, 1],
+    };
 
-impl Vec2 {
-    pub fn min(a: &Self, b: &Self) -> Self {
-        Self(isize::min(a.0, b.0), isize::min(a.1, b.1))
+    /// All 24 rotations, starting with the identity.
+    pub fn all() -> impl Iterator<Item = Self> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        PERMUTATIONS.into_iter().flat_map(|axes| {
+            (0..8)
+                .map(move |flips: usize| Self {
+                    axes,
+                    signs: [0, 1, 2].map(|i| if flips >> i & 1 == 1 { -1 } else { 1 }),
+                })
+                .filter(|rotation| rotation.determinant() == 1)
+        })
     }
-    pub fn max(a: &Self, b: &Self) -> Self {
-        Self(isize::max(a.0, b.0), isize::max(a.1, b.1))
+
+    /// 1 for a rotation, -1 for the mirrored axes `all` leaves out.
+    fn determinant(&self) -> isize {
+        let [a, b, c] = self.axes;
+        let inversions = (a > b) as usize + (a > c) as usize + (b > c) as usize;
+        let parity = if inversions.is_multiple_of(2) { 1 } else { -1 };
+        parity * self.signs.iter().product::<isize>()
     }
 
-    pub fn square_distance(&self, other: &Self) -> usize {
-        ((self.0 - other.0).pow(2) + (self.1 - other.1).pow(2))
-            .try_into()
-            .unwrap()
+    pub fn apply(&self, vector: Vec3) -> Vec3 {
+        Vector([0, 1, 2].map(|i| vector[self.axes[i]] * self.signs[i]))
     }
-}
 
-impl FromStr for Vec2 {
-    type Err = anyhow::Error;
-    fn from_str(input: &str) -> Result<Self> {
-        let mut parts = input.split(',');
-        let x = parts.next().ok_or(anyhow!("No x"))?.parse::<isize>()?;
-        let y = parts.next().ok_or(anyhow!("No y"))?.parse::<isize>()?;
+    /// The rotation applying `other` first, then `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            axes: self.axes.map(|axis| other.axes[axis]),
+            signs: [0, 1, 2].map(|i| self.signs[i] * other.signs[self.axes[i]]),
+        }
+    }
 
-        Ok(Self(x, y))
+    /// The rotation undoing `self`.
+    pub fn invert(&self) -> Self {
+        let mut inverse = *self;
+        for i in 0..3 {
+            inverse.axes[self.axes[i]] = i;
+            inverse.signs[self.axes[i]] = self.signs[i];
+        }
+        inverse
     }
-}
 
-impl Add for Vec2 {
-    type Output = Self;
-    fn add(self, other: Self) -> <Self as Add<Self>>::Output {
-        Self(self.0 + other.0, self.1 + other.1)
+    /// The rotation as a matrix, which multiplies column vectors.
+    pub fn matrix(&self) -> [[isize; 3]; 3] {
+        let mut matrix = [[0; 3]; 3];
+        for i in 0..3 {
+            matrix[i][self.axes[i]] = self.signs[i];
+        }
+        matrix
     }
 }
 
-impl Mul<isize> for Vec2 {
-    type Output = Self;
-    fn mul(self, scalar: isize) -> <Self as Mul<isize>>::Output {
-        Self(self.0 * scalar, self.1 * scalar)
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::IDENTITY
     }
 }
 
-impl Neg for Vec2 {
-    type Output = Self;
-    fn neg(self) -> <Self as Neg>::Output {
-        self * -1
+impl Mul<Vec3> for Rotation {
+    type Output = Vec3;
+    fn mul(self, vector: Vec3) -> Vec3 {
+        self.apply(vector)
     }
 }
 
-impl Sub for Vec2 {
+impl Mul for Rotation {
     type Output = Self;
-    fn sub(self, other: Self) -> <Self as Sub<Self>>::Output {
-        self + (-other)
+    fn mul(self, other: Self) -> Self {
+        self.compose(&other)
     }
 }
 
-impl<'a, 'b> Add<&'b Vec2> for &'a Vec2 {
-    type Output = Vec2;
-    fn add(self, other: &'b Vec2) -> Vec2 {
-        Vec2(self.0 + other.0, self.1 + other.1)
-    }
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
 
-impl<'a, 'b> Mul<isize> for &'a Vec2 {
-    type Output = Vec2;
-    fn mul(self, scalar: isize) -> Vec2 {
-        Vec2(self.0 * scalar, self.1 * scalar)
-    }
-}
+    #[test]
+    fn vectors() {
+        let a: Vec3 = "1,-2,3".parse().unwrap();
+        let b = Vec3::new(4, 5, -6);
+        assert_eq!(a + b, Vec3::new(5, 3, -3));
+        assert_eq!(a - b, Vec3::new(-3, -7, 9));
+        assert_eq!(a.dot(&b), -24);
+        assert_eq!(a.square_distance(&b), 9 + 49 + 81);
+        assert_eq!(a.manhattan_distance(&b), 3 + 7 + 9);
+        assert_eq!(a.chebyshev_distance(&b), 9);
+        let (low, high) = (
+            Vec2::new(isize::MIN, isize::MAX),
+            Vec2::new(isize::MAX, isize::MIN),
+        );
+        assert_eq!(
+            low.checked_distance(&high, Metric::Chebyshev),
+            Some(usize::MAX)
+        );
+        assert_eq!(low.checked_distance(&high, Metric::Manhattan), None);
+        assert_eq!(
+            low.checked_distance(&Vec2::default(), Metric::SquareEuclidean),
+            None
+        );
+        assert_eq!(Vec3::max(&a, &b), Vec3::new(4, 5, 3));
+        assert!("1,2".parse::<Vec3>().is_err());
+        assert!("1,2,3".parse::<Vec2>().is_err());
 
-impl<'a, 'b> Neg for &'a Vec2 {
-    type Output = Vec2;
-    fn neg(self) -> Vec2 {
-        self * -1
+        let mut c = Vec2::default();
+        c += Vec2::new(2, 3);
+        c -= Vec2::new(0, 5);
+        c[0] *= 2;
+        assert_eq!((c.x(), c.y()), (4, -2));
+        assert!(Vec2::new(1, 9) < Vec2::new(2, 0));
     }
-}
 
-impl<'a, 'b> Sub<&'b Vec2> for &'a Vec2 {
-    type Output = Vec2;
-    fn sub(self, other: &'b Vec2) -> Vec2 {
-        self + &(-other)
+    #[test]
+    fn rotations() {
+        let rotations: Vec<_> = Rotation::all().collect();
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+        assert_eq!(rotations.iter().collect::<HashSet<_>>().len(), 24);
+
+        let v = Vec3::new(1, 2, 3);
+        let images: HashSet<_> = rotations.iter().map(|r| r.apply(v)).collect();
+        assert_eq!(images.len(), 24);
+
+        for a in &rotations {
+            assert_eq!(a.compose(&a.invert()), Rotation::IDENTITY);
+            assert_eq!(a.invert().apply(a.apply(v)), v);
+            let m = a.matrix();
+            let product = Vector(m.map(|row| Vector(row).dot(&v)));
+            assert_eq!(product, *a * v);
+            for b in &rotations {
+                assert!(rotations.contains(&(*a * *b)));
+                assert_eq!((*a * *b).apply(v), a.apply(b.apply(v)));
+            }
+        }
     }
 }
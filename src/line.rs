@@ -0,0 +1,137 @@
+use crate::Vector;
+
+/// Whether `Segment::points` ends on the last point of the segment or just before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum End {
+    Inclusive,
+    Exclusive,
+}
+
+/// A straight line between two points with `N` axes.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Segment<const N: usize> {
+    pub from: Vector<N>,
+    pub to: Vector<N>,
+}
+
+impl<const N: usize> Segment<N> {
+    pub fn new(from: Vector<N>, to: Vector<N>) -> Self {
+        Self { from, to }
+    }
+
+    /// How many points past `from` the segment goes through, the distance along the
+    /// axis it changes most on.
+    pub fn steps(&self) -> usize {
+        self.from.chebyshev_distance(&self.to)
+    }
+
+    /// Whether the segment changes along a single axis at most.
+    pub fn is_axis_aligned(&self) -> bool {
+        (0..N)
+            .filter(|&axis| self.from[axis] != self.to[axis])
+            .count()
+            <= 1
+    }
+
+    /// The grid points closest to the segment, one per step, from `from` to `to`.
+    /// Like Bresenham's algorithm, the axis that changes most moves by one at every
+    /// step and the others by zero or one, so any slope works, in any dimension.
+    pub fn points(&self, end: End) -> Points<N> {
+        let steps = self.steps();
+        Points {
+            from: self.from,
+            delta: self.to - self.from,
+            steps,
+            next: 0,
+            end: match end {
+                End::Inclusive => steps + 1,
+                End::Exclusive => steps,
+            },
+        }
+    }
+}
+
+/// Iterator over the points of a segment, see `Segment::points`.
+#[derive(Debug, Clone)]
+pub struct Points<const N: usize> {
+    from: Vector<N>,
+    delta: Vector<N>,
+    steps: usize,
+    next: usize,
+    end: usize,
+}
+
+impl<const N: usize> Points<N> {
+    /// The point `step` steps along: each axis moves by its share of the delta, and
+    /// lands on the nearest integer. Halves round up, the same way whichever end the
+    /// segment starts from, so a segment and its reverse cover the same points.
+    fn point(&self, step: usize) -> Vector<N> {
+        if self.steps == 0 {
+            return self.from;
+        }
+        let (step, steps) = (step as i128, self.steps as i128);
+        let mut point = self.from;
+        for axis in 0..N {
+            let exact = self.from[axis] as i128 * steps + self.delta[axis] as i128 * step;
+            point[axis] = (2 * exact + steps).div_euclid(2 * steps) as isize;
+        }
+        point
+    }
+}
+
+impl<const N: usize> Iterator for Points<N> {
+    type Item = Vector<N>;
+    fn next(&mut self) -> Option<Vector<N>> {
+        if self.next >= self.end {
+            return None;
+        }
+        self.next += 1;
+        Some(self.point(self.next - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.end.saturating_sub(self.next);
+        (left, Some(left))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Points<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3};
+
+    #[test]
+    fn rasterize() {
+        let points = |segment: Segment<2>, end| {
+            segment
+                .points(end)
+                .map(|p| (p.x(), p.y()))
+                .collect::<Vec<_>>()
+        };
+        let diagonal = Segment::new(Vec2::new(3, 3), Vec2::new(1, 5));
+        assert_eq!(points(diagonal, End::Inclusive), [(3, 3), (2, 4), (1, 5)]);
+        assert_eq!(points(diagonal, End::Exclusive), [(3, 3), (2, 4)]);
+
+        let steep = Segment::new(Vec2::new(0, 0), Vec2::new(2, 4));
+        assert_eq!(
+            points(steep, End::Inclusive),
+            [(0, 0), (1, 1), (1, 2), (2, 3), (2, 4)]
+        );
+        let mut reversed = points(Segment::new(steep.to, steep.from), End::Inclusive);
+        reversed.reverse();
+        assert_eq!(reversed, points(steep, End::Inclusive));
+
+        let point = Segment::new(Vec2::new(7, 7), Vec2::new(7, 7));
+        assert_eq!(points(point, End::Inclusive), [(7, 7)]);
+        assert_eq!(points(point, End::Exclusive), []);
+
+        let space = Segment::new(Vec3::new(0, 0, 0), Vec3::new(6, -3, 2));
+        let path: Vec<_> = space.points(End::Inclusive).collect();
+        assert_eq!(path.len(), 7);
+        assert!(path.windows(2).all(|w| w[0].chebyshev_distance(&w[1]) == 1));
+        assert_eq!(path[6], space.to);
+        assert!(!space.is_axis_aligned() && Segment::new(space.to, space.to).is_axis_aligned());
+    }
+}
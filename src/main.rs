@@ -1,105 +1,497 @@
-use std::fs;
-use std::time::Instant;
+use anyhow::{anyhow, bail, Context, Result};
+use aoc2021::answers::{ExpectedAnswers, Verdict};
+use aoc2021::bench::{bench_part, print_bench_table};
+use aoc2021::days::registry;
+use aoc2021::history::{current_revision, print_comparison_table, Entry, History};
+use aoc2021::report::{to_csv, to_json, OutputFormat, ReportWriter, ToRecord};
+use aoc2021::rng::Rng;
+use aoc2021::runner::{
+    calendar, print_calendar, run_part, run_part_in_child, DaySelection, InputSource, PartRun,
+    RUN_TABLE,
+};
+use aoc2021::scaffold::new_day;
+use aoc2021::solution::{DynSolution, Part, Registry};
+use aoc2021::visualize::{visualize_part, Playback};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use structopt::StructOpt;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc2021::alloc::CountingAllocator = aoc2021::alloc::CountingAllocator;
 
 #[derive(StructOpt)]
-struct Cli {
-    day: i32,
-    part: i32,
+enum Cli {
+    /// Show the calendar, with how far each day is solved
+    List {
+        /// Output format: table, json or csv
+        #[structopt(long, default_value = "table")]
+        format: OutputFormat,
+    },
+    /// Run the selected days and print their answers
+    Run {
+        #[structopt(flatten)]
+        selection: Selection,
+        /// Play back how simulation days get to their answers instead
+        #[structopt(long)]
+        visualize: bool,
+        /// With --visualize, write the frames as text files under this directory
+        #[structopt(long, parse(from_os_str))]
+        frames_dir: Option<PathBuf>,
+        /// With --visualize, frames per second when playing in the terminal
+        #[structopt(long, default_value = "10")]
+        fps: u32,
+        #[structopt(flatten)]
+        timeout: Timeout,
+    },
+    /// Compare answers with the expected ones, failing on any mismatch
+    Verify {
+        #[structopt(flatten)]
+        selection: Selection,
+        /// Expected answers file, `answers.toml` in the input directory by default
+        #[structopt(long, parse(from_os_str))]
+        answers: Option<PathBuf>,
+        #[structopt(flatten)]
+        timeout: Timeout,
+    },
+    /// Time parsing and solving separately over many iterations
+    Bench {
+        #[structopt(flatten)]
+        selection: Selection,
+        /// Number of timed iterations per part
+        #[structopt(short = "n", long, default_value = "10")]
+        iterations: usize,
+        /// Number of untimed iterations before timing starts
+        #[structopt(long, default_value = "1")]
+        warmup: usize,
+        #[structopt(flatten)]
+        history: HistoryFile,
+        /// Leave the results out of the history
+        #[structopt(long)]
+        no_save: bool,
+    },
+    /// Compare the benchmarks of two revisions in the history, failing on regressions
+    Compare {
+        /// A day, a range of days such as `1..=10`, or `all`
+        #[structopt(default_value = "all")]
+        days: DaySelection,
+        /// Revision to compare, the one checked out by default
+        #[structopt(long)]
+        revision: Option<String>,
+        /// Revision to compare against, by default the last one benchmarked before
+        #[structopt(long)]
+        baseline: Option<String>,
+        /// How many percent slower a part can get before it counts as a regression
+        #[structopt(long, default_value = "10")]
+        threshold: f64,
+        #[structopt(flatten)]
+        history: HistoryFile,
+        /// Output format: table, json or csv
+        #[structopt(long, default_value = "table")]
+        format: OutputFormat,
+    },
+    /// Write a random puzzle input for a day
+    Generate {
+        day: u8,
+        /// Seed for the generator, random when omitted. It is printed to stderr so the
+        /// input can be generated again
+        #[structopt(long)]
+        seed: Option<u64>,
+        /// How big the input is, such as a number of lines or the side of a grid,
+        /// depending on the day
+        #[structopt(long)]
+        size: Option<usize>,
+        /// Write the input to this file instead of stdout
+        #[structopt(short, long, parse(from_os_str))]
+        output: Option<PathBuf>,
+    },
+    /// Start a new day: write its module from a template, with an empty sample, and
+    /// register it
+    New {
+        day: u8,
+        /// The puzzle's title
+        #[structopt(long, default_value = "")]
+        title: String,
+        /// Directory of the day modules, holding the `mod.rs` that registers them
+        #[structopt(long, default_value = "src/days", parse(from_os_str))]
+        days_dir: PathBuf,
+        /// Directory of the sample inputs
+        #[structopt(long, default_value = "examples", parse(from_os_str))]
+        examples_dir: PathBuf,
+    },
 }
 
-fn main() {
-    let Cli { day, part } = Cli::from_args();
-    let now = Instant::now();
-    let file_name = format!("src/day{:02}/input.txt", day);
-    let input_file = fs::read_to_string(file_name).expect("Failed to read file");
-    let file_read_time = now.elapsed();
-    let result = match (day, part) {
-        (1, 1) => Ok(day01::part1(input_file)),
-        (1, 2) => Ok(day01::part2(input_file)),
-        (2, 1) => Ok(day02::part1(input_file).try_into().unwrap()),
-        (2, 2) => Ok(day02::part2(input_file).try_into().unwrap()),
-        (3, 1) => Ok(day03::part1(input_file)),
-        (3, 2) => Ok(day03::part2(input_file)),
-        (4, 1) => Ok(day04::part1(input_file)),
-        (4, 2) => Ok(day04::part2(input_file)),
-        (5, 1) => Ok(day05::part1(input_file)),
-        (5, 2) => Ok(day05::part2(input_file)),
-        (6, 1) => Ok(day06::part1(input_file)),
-        (6, 2) => Ok(day06::part2(input_file)),
-        (7, 1) => Ok(day07::part1(input_file)),
-        (7, 2) => Ok(day07::part2(input_file)),
-        (8, 1) => Ok(day08::part1(input_file)),
-        (8, 2) => Ok(day08::part2(input_file)),
-        (9, 1) => Ok(day09::part1(input_file)),
-        (9, 2) => Ok(day09::part2(input_file)),
-        (10, 1) => Ok(day10::part1(input_file)),
-        (10, 2) => Ok(day10::part2(input_file)),
-        (11, 1) => Ok(day11::part1(input_file)),
-        (11, 2) => Ok(day11::part2(input_file)),
-        (12, 1) => Ok(day12::part1(input_file)),
-        (12, 2) => Ok(day12::part2(input_file)),
-        (13, 1) => Ok(day13::part1(input_file)),
-        (13, 2) => Ok(day13::part2(input_file)),
-        (14, 1) => Ok(day14::part1(input_file)),
-        (14, 2) => Ok(day14::part2(input_file)),
-        (15, 1) => Ok(day15::part1(input_file)),
-        (15, 2) => Ok(day15::part2(input_file)),
-        (16, 1) => Ok(day16::part1(input_file)),
-        (16, 2) => Ok(day16::part2(input_file)),
-        (17, 1) => Ok(day17::part1(input_file)),
-        (17, 2) => Ok(day17::part2(input_file)),
-        (18, 1) => Ok(day18::part1(input_file)),
-        (18, 2) => Ok(day18::part2(input_file)),
-        (19, 1) => Ok(day19::part1(input_file)),
-        (19, 2) => Ok(day19::part2(input_file)),
-        (20, 1) => Ok(day20::part1(input_file)),
-        (20, 2) => Ok(day20::part2(input_file)),
-        (21, 1) => Ok(day21::part1(input_file)),
-        (21, 2) => Ok(day21::part2(input_file)),
-        (22, 1) => Ok(day22::part1(input_file)),
-        (22, 2) => Ok(day22::part2(input_file)),
-        (23, 1) => Ok(day23::part1(input_file)),
-        (23, 2) => Ok(day23::part2(input_file)),
-        (24, 1) => Ok(day24::part1(input_file)),
-        (24, 2) => Ok(day24::part2(input_file)),
-        (25, 1) => Ok(day25::part1(input_file)),
-        (25, 2) => Ok(day25::part2(input_file)),
-        _ => Err("Unsupported solution"),
-    };
+#[derive(StructOpt)]
+struct Selection {
+    /// A day, a range of days such as `1..=10`, or `all`
+    #[structopt(default_value = "all")]
+    days: DaySelection,
+    /// Runs both parts when omitted
+    part: Option<u8>,
+    /// Read the puzzle input from this file instead, or from stdin when `-`
+    #[structopt(long, parse(from_os_str))]
+    input: Option<PathBuf>,
+    /// Directory holding a `dayNN/input.txt` for each day
+    #[structopt(
+        long,
+        env = "AOC_INPUT_DIR",
+        default_value = "src/days",
+        parse(from_os_str)
+    )]
+    input_dir: PathBuf,
+    /// Output format: table, json or csv
+    #[structopt(long, default_value = "table")]
+    format: OutputFormat,
+}
+
+#[derive(StructOpt)]
+struct HistoryFile {
+    /// File keeping the benchmark results of every revision
+    #[structopt(
+        long = "history",
+        env = "AOC_BENCH_HISTORY",
+        default_value = "bench-history.csv",
+        parse(from_os_str)
+    )]
+    path: PathBuf,
+}
+
+#[derive(StructOpt)]
+struct Timeout {
+    /// Give up on a part after this many seconds, reporting it as TIMEOUT, and move
+    /// on to the next one. Each part then runs in a process of its own, which is
+    /// killed when it runs out of time
+    #[structopt(long = "timeout", env = "AOC_TIMEOUT")]
+    seconds: Option<f64>,
+}
 
-    println!(
-        "Day {} Part {} - Result is: {} -- Took {:?} (file read took {:?})",
-        day,
+impl Timeout {
+    fn duration(&self) -> Result<Option<Duration>> {
+        self.seconds
+            .map(|seconds| {
+                Duration::try_from_secs_f64(seconds)
+                    .map_err(|_| anyhow!("Invalid timeout {}", seconds))
+            })
+            .transpose()
+    }
+}
+
+/// Exit codes, so scripts can tell wrong answers apart from parts that failed to run.
+const EXIT_WRONG_ANSWER: i32 = 1;
+const EXIT_ERROR: i32 = 2;
+/// `compare` found a regression, its equivalent of a wrong answer.
+const EXIT_REGRESSION: i32 = 1;
+
+/// Resolves the selection to the parts to run, each with its puzzle input.
+fn select_parts(
+    registry: &Registry,
+    selection: Selection,
+) -> Result<Vec<(&'static dyn DynSolution, Part, String)>> {
+    let Selection {
+        days,
         part,
-        result.expect(&format!("day {} part {} not implemented", day, part)),
-        now.elapsed(),
-        file_read_time,
-    );
+        input,
+        input_dir,
+        ..
+    } = selection;
+    let parts = match part {
+        Some(n) => vec![Part::from_number(n).ok_or(anyhow!("There is no part {}", n))?],
+        None => Part::ALL.to_vec(),
+    };
+
+    let solutions: Vec<_> = registry
+        .iter()
+        .filter(|solution| days.contains(solution.metadata().day))
+        .collect();
+    if solutions.is_empty() {
+        bail!("No implemented days in {:?}", days);
+    }
+    let input_source = InputSource::new(input, input_dir);
+    if input_source.is_shared() && solutions.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+
+    let mut selected = Vec::new();
+    for solution in solutions {
+        let day = solution.metadata().day;
+        let input_file = input_source.read(day)?;
+        for &part in &parts {
+            selected.push((solution, part, input_file.clone()));
+        }
+    }
+    Ok(selected)
+}
+
+/// Keeps the successful results, printing each error (with its causes) to stderr.
+/// The flag is set when there was at least one error.
+fn partition_errors<T>(results: impl Iterator<Item = Result<T>>) -> (Vec<T>, bool) {
+    let mut failed = false;
+    let items = results
+        .filter_map(|result| {
+            result
+                .map_err(|err| {
+                    eprintln!("Error: {:?}", err);
+                    failed = true;
+                })
+                .ok()
+        })
+        .collect();
+    (items, failed)
+}
+
+/// Runs a part in a child process, with this same binary running only that part.
+fn child_command(solution: &dyn DynSolution, part: Part) -> Result<Command> {
+    let mut command = Command::new(std::env::current_exe()?);
+    command
+        .arg("run")
+        .arg(solution.metadata().day.to_string())
+        .arg(part.number().to_string())
+        .args(["--input", "-", "--format", "json"])
+        .env_remove("AOC_TIMEOUT");
+    Ok(command)
+}
+
+/// Runs the selected parts, printing each as soon as it is done so that nothing is
+/// lost when the run is cut short. With `expected` answers, each answer is checked
+/// before it is printed. The flag is set when a part failed, panicked or timed out.
+fn run_selection(
+    registry: &Registry,
+    selection: Selection,
+    timeout: Timeout,
+    expected: Option<&ExpectedAnswers>,
+) -> Result<(Vec<PartRun>, bool)> {
+    let format = selection.format;
+    let timeout = timeout.duration()?;
+    let parts = select_parts(registry, selection)?;
+
+    let mut report = ReportWriter::new(format, RUN_TABLE);
+    let mut failed = false;
+    for (solution, part, input) in parts {
+        let run = match timeout {
+            Some(_) => child_command(solution, part)
+                .and_then(|command| run_part_in_child(solution, part, input, timeout, command)),
+            None => run_part(solution, part, input),
+        };
+        match run {
+            Ok(mut run) => {
+                if let (Some(expected), None) = (expected, &run.failure) {
+                    run.verdict = Some(expected.check(run.day, run.part, &run.answer.to_string()));
+                }
+                report.write(run);
+            }
+            Err(err) => {
+                eprintln!("Error: {:?}", err);
+                failed = true;
+            }
+        }
+    }
+    let runs = report.finish();
+    let unsolved = runs.iter().any(|run| run.failure.is_some());
+    Ok((runs, failed || unsolved))
+}
+
+fn print_report<T: ToRecord>(format: OutputFormat, items: &[T], print_table: fn(&[T])) {
+    match format {
+        OutputFormat::Table => print_table(items),
+        OutputFormat::Json => println!("{}", to_json(items)),
+        OutputFormat::Csv => println!("{}", to_csv(items)),
+    }
+}
+
+/// Runs the command, returning the exit code.
+fn run(cli: Cli, registry: &Registry) -> Result<i32> {
+    match cli {
+        Cli::List { format } => {
+            print_report(format, &calendar(registry), print_calendar);
+            Ok(0)
+        }
+        Cli::Run {
+            selection,
+            visualize: true,
+            frames_dir,
+            fps,
+            ..
+        } => {
+            let playback = match frames_dir {
+                Some(dir) => Playback::Directory(dir),
+                None => Playback::Terminal { fps },
+            };
+            let results =
+                select_parts(registry, selection)?
+                    .into_iter()
+                    .map(|(solution, part, input)| {
+                        let day = solution.metadata().day;
+                        match visualize_part(solution, part, input, &playback)? {
+                            None => eprintln!("Day {} part {} has no visualization", day, part),
+                            Some(frames) => {
+                                if let Playback::Directory(dir) = &playback {
+                                    println!(
+                                        "Day {} part {}: wrote {} frames to {:?}",
+                                        day, part, frames, dir
+                                    );
+                                }
+                            }
+                        }
+                        Ok(())
+                    });
+            let (_, failed) = partition_errors(results);
+
+            Ok(if failed { EXIT_ERROR } else { 0 })
+        }
+        Cli::Run {
+            selection, timeout, ..
+        } => {
+            let (_, failed) = run_selection(registry, selection, timeout, None)?;
+
+            Ok(if failed { EXIT_ERROR } else { 0 })
+        }
+        Cli::Verify {
+            selection,
+            answers,
+            timeout,
+        } => {
+            let answers_file = answers.unwrap_or_else(|| selection.input_dir.join("answers.toml"));
+            let expected = ExpectedAnswers::load(&answers_file)?;
+            let (runs, failed) = run_selection(registry, selection, timeout, Some(&expected))?;
+
+            if failed {
+                Ok(EXIT_ERROR)
+            } else if runs
+                .iter()
+                .any(|run| matches!(run.verdict, Some(Verdict::Fail { .. })))
+            {
+                Ok(EXIT_WRONG_ANSWER)
+            } else {
+                Ok(0)
+            }
+        }
+        Cli::Bench {
+            selection,
+            iterations,
+            warmup,
+            history,
+            no_save,
+        } => {
+            let format = selection.format;
+            let (benches, failed) =
+                partition_errors(select_parts(registry, selection)?.into_iter().map(
+                    |(solution, part, input)| {
+                        bench_part(solution, part, &input, warmup, iterations)
+                    },
+                ));
+            print_report(format, &benches, print_bench_table);
+
+            if !no_save && !benches.is_empty() {
+                let revision = current_revision();
+                let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+                let entries: Vec<_> = benches
+                    .iter()
+                    .map(|bench| Entry::new(bench, &revision, timestamp))
+                    .collect();
+                History::append(&history.path, &entries)?;
+                eprintln!(
+                    "Saved {} results of revision {} to {:?}",
+                    entries.len(),
+                    revision,
+                    history.path
+                );
+            }
+
+            Ok(if failed { EXIT_ERROR } else { 0 })
+        }
+        Cli::Compare {
+            days,
+            revision,
+            baseline,
+            threshold,
+            history,
+            format,
+        } => {
+            let history_file = History::load(&history.path)?;
+            let revision = revision.unwrap_or_else(current_revision);
+            if !history_file.has_revision(&revision) {
+                bail!(
+                    "Revision {} has no benchmarks in {:?}, run `bench` first",
+                    revision,
+                    history.path
+                );
+            }
+            let baseline = match baseline {
+                Some(baseline) if history_file.has_revision(&baseline) => baseline,
+                Some(baseline) => bail!("Revision {} has no benchmarks", baseline),
+                None => history_file
+                    .previous_revision(&revision)
+                    .ok_or(anyhow!(
+                        "There is no other revision to compare {} with",
+                        revision
+                    ))?
+                    .to_string(),
+            };
+
+            let comparisons: Vec<_> = history_file
+                .compare(&baseline, &revision, threshold)
+                .into_iter()
+                .filter(|comparison| days.contains(comparison.day))
+                .collect();
+            if format == OutputFormat::Table {
+                println!("Comparing {} with {}", revision, baseline);
+            }
+            print_report(format, &comparisons, print_comparison_table);
+
+            if comparisons.iter().any(|comparison| comparison.regressed) {
+                Ok(EXIT_REGRESSION)
+            } else {
+                Ok(0)
+            }
+        }
+        Cli::Generate {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let solution = registry
+                .get(day)
+                .ok_or(anyhow!("Day {} is not implemented", day))?;
+            let seed = match seed {
+                Some(seed) => seed,
+                None => {
+                    let seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+                    eprintln!("Seed: {}", seed);
+                    seed
+                }
+            };
+            let input = solution
+                .generate(&mut Rng::new(seed), size)
+                .ok_or(anyhow!("Day {} has no input generator", day))?;
+            match output {
+                Some(path) => std::fs::write(&path, input + "\n")
+                    .with_context(|| format!("Failed to write {:?}", path))?,
+                None => println!("{}", input),
+            }
+
+            Ok(0)
+        }
+        Cli::New {
+            day,
+            title,
+            days_dir,
+            examples_dir,
+        } => {
+            for path in new_day(&days_dir, &examples_dir, day, &title)? {
+                println!("Wrote {}", path.display());
+            }
+
+            Ok(0)
+        }
+    }
+}
+
+fn main() {
+    let code = run(Cli::from_args(), &registry()).unwrap_or_else(|err| {
+        eprintln!("Error: {:?}", err);
+        EXIT_ERROR
+    });
+    std::process::exit(code);
 }
@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+/// Each glyph is followed by one blank column.
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+/// The letters known to appear in puzzle answers, in the 4x6 dot-matrix font.
+const GLYPHS: [(char, &str); 17] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Bit `y * GLYPH_WIDTH + x` is set for every lit pixel of a glyph.
+fn glyph_bits(glyph: &str) -> u32 {
+    glyph
+        .lines()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| 1 << (y * GLYPH_WIDTH + x))
+        })
+        .sum()
+}
+
+/// Reads the letters spelled by a set of lit `(x, y)` points. The first letter is
+/// expected to start at the origin, with the rest following every five columns.
+pub fn recognize_points<I>(points: I) -> Result<String>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let points: HashSet<_> = points.into_iter().collect();
+    let max_x = points
+        .iter()
+        .map(|&(x, _)| x)
+        .max()
+        .ok_or(anyhow!("Nothing to recognize"))?;
+    if let Some((x, y)) = points.iter().find(|&&(_, y)| y >= GLYPH_HEIGHT) {
+        return Err(anyhow!("Point ({}, {}) is below the letters", x, y));
+    }
+
+    (0..=max_x / GLYPH_SPACING)
+        .map(|i| {
+            let left = i * GLYPH_SPACING;
+            let mut bits = 0;
+            for y in 0..GLYPH_HEIGHT {
+                for x in 0..GLYPH_WIDTH {
+                    if points.contains(&(left + x, y)) {
+                        bits |= 1 << (y * GLYPH_WIDTH + x);
+                    }
+                }
+                if points.contains(&(left + GLYPH_WIDTH, y)) {
+                    return Err(anyhow!(
+                        "Letter {} is wider than {} columns",
+                        i,
+                        GLYPH_WIDTH
+                    ));
+                }
+            }
+
+            GLYPHS
+                .iter()
+                .find(|(_, glyph)| glyph_bits(glyph) == bits)
+                .map(|&(c, _)| c)
+                .ok_or(anyhow!("Letter {} is not a known glyph", i))
+        })
+        .collect()
+}
+
+/// Reads the letters drawn in a text grid, where `lit` marks the lit pixels.
+pub fn recognize_grid(grid: &str, lit: char) -> Result<String> {
+    recognize_points(grid.lines().enumerate().flat_map(|(y, row)| {
+        row.chars()
+            .enumerate()
+            .filter(move |&(_, c)| c == lit)
+            .map(move |(x, _)| (x, y))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognize_text() {
+        let grid = "\
+####  ##  #### #  # #    #  # #### ####
+#    #  # #    #  # #    #  #    # #
+###  #    ###  #### #    ####   #  ###
+#    #    #    #  # #    #  #  #   #
+#    #  # #    #  # #    #  # #    #
+####  ##  #    #  # #### #  # #### #";
+
+        assert_eq!(recognize_grid(grid, '#').unwrap(), "ECFHLHZF");
+    }
+
+    #[test]
+    fn every_glyph() {
+        let grid: Vec<String> = (0..GLYPH_HEIGHT)
+            .map(|y| {
+                GLYPHS
+                    .iter()
+                    .map(|(_, glyph)| glyph.lines().nth(y).unwrap())
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect();
+
+        assert_eq!(
+            recognize_grid(&grid.join("\n"), '#').unwrap(),
+            "ABCEFGHIJKLOPRSUZ"
+        );
+    }
+
+    #[test]
+    fn unknown_glyph() {
+        assert!(recognize_points([(0, 0), (1, 1)]).is_err());
+        assert!(recognize_points([]).is_err());
+    }
+}
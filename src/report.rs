@@ -0,0 +1,346 @@
+use crate::answer::Answer;
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        match input {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(anyhow!(
+                "Unknown format {}, expected table, json or csv",
+                input
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Str(String),
+    Int(i128),
+    Null,
+}
+
+impl From<&Answer> for Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => Self::Int(*n),
+            Answer::Str(s) => Self::Str(s.clone()),
+            Answer::None => Self::Null,
+        }
+    }
+}
+
+/// Durations are reported in nanoseconds.
+impl From<Duration> for Value {
+    fn from(duration: Duration) -> Self {
+        Self::Int(duration.as_nanos() as i128)
+    }
+}
+
+/// One row of machine-readable output, as ordered `(field, value)` pairs.
+/// Every record in a report is expected to have the same fields.
+pub type Record = Vec<(&'static str, Value)>;
+
+pub trait ToRecord {
+    fn to_record(&self) -> Record;
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// An item as a line of a JSON array.
+fn json_object(record: Record) -> String {
+    let fields: Vec<_> = record
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match value {
+                Value::Str(s) => json_string(&s),
+                Value::Int(n) => n.to_string(),
+                Value::Null => "null".to_string(),
+            };
+            format!("{}: {}", json_string(name), value)
+        })
+        .collect();
+    format!("  {{{}}}", fields.join(", "))
+}
+
+fn csv_header(record: &Record) -> String {
+    let names: Vec<_> = record.iter().map(|(name, _)| *name).collect();
+    names.join(",")
+}
+
+fn csv_line(record: Record) -> String {
+    let fields: Vec<_> = record
+        .into_iter()
+        .map(|(_, value)| match value {
+            Value::Str(s) => csv_field(&s),
+            Value::Int(n) => n.to_string(),
+            Value::Null => String::new(),
+        })
+        .collect();
+    fields.join(",")
+}
+
+pub fn to_json<T: ToRecord>(items: &[T]) -> String {
+    let objects: Vec<_> = items
+        .iter()
+        .map(|item| json_object(item.to_record()))
+        .collect();
+
+    if objects.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", objects.join(",\n"))
+    }
+}
+
+pub fn to_csv<T: ToRecord>(items: &[T]) -> String {
+    let records: Vec<_> = items.iter().map(ToRecord::to_record).collect();
+    let mut lines = Vec::new();
+    if let Some(first) = records.first() {
+        lines.push(csv_header(first));
+    }
+    lines.extend(records.into_iter().map(csv_line));
+    lines.join("\n")
+}
+
+/// How to print a report as a table: a header, a row per item, and a footer that
+/// can sum up all of them.
+pub struct Table<T> {
+    pub header: fn(),
+    pub row: fn(&T),
+    pub footer: fn(&[T]),
+}
+
+/// Prints a report an item at a time, as the items come, so the ones printed so far
+/// aren't lost when something goes wrong later on. Returns the items when finished.
+pub struct ReportWriter<T> {
+    format: OutputFormat,
+    table: Table<T>,
+    items: Vec<T>,
+}
+
+impl<T: ToRecord> ReportWriter<T> {
+    pub fn new(format: OutputFormat, table: Table<T>) -> Self {
+        if format == OutputFormat::Table {
+            (table.header)();
+        }
+        Self {
+            format,
+            table,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn write(&mut self, item: T) {
+        let first = self.items.is_empty();
+        match self.format {
+            OutputFormat::Table => (self.table.row)(&item),
+            OutputFormat::Json => {
+                let separator = if first { "[" } else { "," };
+                println!("{}", separator);
+                print!("{}", json_object(item.to_record()));
+            }
+            OutputFormat::Csv => {
+                let record = item.to_record();
+                if first {
+                    println!("{}", csv_header(&record));
+                }
+                println!("{}", csv_line(record));
+            }
+        }
+        self.items.push(item);
+    }
+
+    pub fn finish(self) -> Vec<T> {
+        match self.format {
+            OutputFormat::Table => (self.table.footer)(&self.items),
+            OutputFormat::Json if self.items.is_empty() => println!("[]"),
+            OutputFormat::Json => println!("\n]"),
+            OutputFormat::Csv => {}
+        }
+        self.items
+    }
+}
+
+/// Reads back the records of a report written by `to_json`, by field name.
+pub fn from_json(input: &str) -> Result<Vec<HashMap<String, Value>>> {
+    let mut chars = input.chars().peekable();
+    let records = parse_json_list(&mut chars, '[', ']', |chars| {
+        parse_json_list(chars, '{', '}', |chars| {
+            let name = parse_json_string(chars)?;
+            expect_json(chars, ':')?;
+            Ok((name, parse_json_value(chars)?))
+        })
+        .map(|fields| fields.into_iter().collect())
+    })?;
+    skip_json_whitespace(&mut chars);
+    if let Some(c) = chars.next() {
+        bail!("Unexpected {:?} after the report", c);
+    }
+    Ok(records)
+}
+
+fn skip_json_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect_json(chars: &mut Peekable<Chars>, expected: char) -> Result<()> {
+    skip_json_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        c => Err(anyhow!("Expected {:?}, found {:?}", expected, c)),
+    }
+}
+
+/// A list of `item`s between `open` and `close`, separated by commas.
+fn parse_json_list<T>(
+    chars: &mut Peekable<Chars>,
+    open: char,
+    close: char,
+    mut item: impl FnMut(&mut Peekable<Chars>) -> Result<T>,
+) -> Result<Vec<T>> {
+    expect_json(chars, open)?;
+    skip_json_whitespace(chars);
+    let mut items = Vec::new();
+    if chars.next_if_eq(&close).is_some() {
+        return Ok(items);
+    }
+    loop {
+        items.push(item(chars)?);
+        skip_json_whitespace(chars);
+        match chars.next() {
+            Some(',') => {}
+            Some(c) if c == close => return Ok(items),
+            c => bail!("Expected ',' or {:?}, found {:?}", close, c),
+        }
+    }
+}
+
+fn parse_json_string(chars: &mut Peekable<Chars>) -> Result<String> {
+    expect_json(chars, '"')?;
+    let mut s = String::new();
+    loop {
+        match chars.next().ok_or(anyhow!("Unterminated string"))? {
+            '"' => return Ok(s),
+            '\\' => s.push(match chars.next() {
+                Some('n') => '\n',
+                Some('r') => '\r',
+                Some('t') => '\t',
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or(anyhow!("Invalid escape \\u{}", hex))?
+                }
+                Some(c @ ('"' | '\\' | '/')) => c,
+                c => bail!("Invalid escape {:?}", c),
+            }),
+            c => s.push(c),
+        }
+    }
+}
+
+/// The values `to_json` writes: strings, integers and null.
+fn parse_json_value(chars: &mut Peekable<Chars>) -> Result<Value> {
+    skip_json_whitespace(chars);
+    match chars.peek() {
+        Some('"') => Ok(Value::Str(parse_json_string(chars)?)),
+        Some('n') => {
+            let word: String = chars.by_ref().take(4).collect();
+            if word != "null" {
+                bail!("Unexpected {:?}", word);
+            }
+            Ok(Value::Null)
+        }
+        _ => {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|&c| c == '-' || c.is_ascii_digit()) {
+                number.push(c);
+            }
+            Ok(Value::Int(number.parse().map_err(|_| {
+                anyhow!("Expected a value, found {:?}", chars.peek())
+            })?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row(&'static str, i128);
+
+    impl ToRecord for Row {
+        fn to_record(&self) -> Record {
+            vec![
+                ("name", Value::Str(self.0.to_string())),
+                ("n", Value::Int(self.1)),
+                ("none", Value::Null),
+            ]
+        }
+    }
+
+    #[test]
+    fn json() {
+        let rows = [Row("a \"b\"\n", 1), Row("c", 2)];
+        assert_eq!(
+            to_json(&rows),
+            "[\n  {\"name\": \"a \\\"b\\\"\\n\", \"n\": 1, \"none\": null},\n  {\"name\": \"c\", \"n\": 2, \"none\": null}\n]"
+        );
+        assert_eq!(to_json::<Row>(&[]), "[]");
+
+        let records = from_json(&to_json(&rows)).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["name"], Value::Str("a \"b\"\n".to_string()));
+        assert_eq!(records[1]["n"], Value::Int(2));
+        assert_eq!(records[1]["none"], Value::Null);
+        assert_eq!(from_json(" [ ] ").unwrap(), []);
+        assert!(from_json("[{\"n\": 1}").is_err());
+        assert!(from_json("[{\"n\": x}]").is_err());
+    }
+
+    #[test]
+    fn csv() {
+        let rows = [Row("a, \"b\"", 1), Row("c", 2)];
+        assert_eq!(to_csv(&rows), "name,n,none\n\"a, \"\"b\"\"\",1,\nc,2,");
+    }
+}
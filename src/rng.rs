@@ -0,0 +1,78 @@
+use std::ops::RangeInclusive;
+
+/// A small seedable random number generator (SplitMix64). Good enough to generate
+/// puzzle inputs, and the same seed always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in the inclusive range.
+    pub fn range(&mut self, range: RangeInclusive<isize>) -> isize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        let span = end.abs_diff(start) as u64 + 1;
+        // A span of 0 means the whole of u64 wrapped around
+        let offset = if span == 0 {
+            self.next_u64()
+        } else {
+            self.next_u64() % span
+        };
+        start.wrapping_add(offset as isize)
+    }
+
+    /// True with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+            let n = a.range(-3..=3);
+            assert!((-3..=3).contains(&n));
+            assert_eq!(b.range(-3..=3), n);
+        }
+
+        let mut items: Vec<_> = (0..10).collect();
+        a.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
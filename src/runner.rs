@@ -0,0 +1,840 @@
+use crate::alloc::{self, format_bytes, AllocStats};
+use crate::answer::Answer;
+use crate::answers::Verdict;
+use crate::report::{from_json, Record, Table, ToRecord, Value};
+use crate::solution::{DynSolution, Metadata, Part, Registry, Status};
+use anyhow::{anyhow, bail, Context, Result};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Which days to run: `all`, a single day (`5`) or a range (`1..10`, `1..=10`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        Self(1..=25)
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self> {
+        if input == "all" {
+            return Ok(Self::all());
+        }
+        let range = if let Some((from, to)) = input.split_once("..=") {
+            from.parse()?..=to.parse()?
+        } else if let Some((from, to)) = input.split_once("..") {
+            let to: u8 = to.parse()?;
+            from.parse()?..=to.checked_sub(1).ok_or(anyhow!("Empty range {}", input))?
+        } else {
+            let day = input.parse()?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(anyhow!("Empty range {}", input));
+        }
+        Ok(Self(range))
+    }
+}
+
+/// Where puzzle inputs come from: a single file, stdin, or `<dir>/dayNN/input.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    /// `input` wins over `input_dir`; an input of `-` means stdin.
+    pub fn new(input: Option<PathBuf>, input_dir: PathBuf) -> Self {
+        match input {
+            Some(path) if path.as_os_str() == "-" => Self::Stdin,
+            Some(path) => Self::File(path),
+            None => Self::Directory(input_dir),
+        }
+    }
+
+    /// Whether every day reads the same input, so only one day can be run from it.
+    pub fn is_shared(&self) -> bool {
+        !matches!(self, Self::Directory(_))
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        match self {
+            Self::File(path) => {
+                std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))
+            }
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read stdin")?;
+                Ok(input)
+            }
+            Self::Directory(dir) => {
+                let path = dir.join(format!("day{:02}", day)).join("input.txt");
+                std::fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: Part,
+    /// Whether the answer is actually computed.
+    pub implementation: Status,
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// Set when allocations are counted (see `alloc::ENABLED`), for parsing and solving together.
+    pub allocations: Option<AllocStats>,
+    /// Set when the part didn't get to an answer.
+    pub failure: Option<Failure>,
+    /// Set when the answer was checked against an expected one.
+    pub verdict: Option<Verdict>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Returned an error, with its causes.
+    Error(String),
+    /// Ran out of time (see `run_part_in_child`).
+    Timeout,
+    /// Panicked, with the panic message and where it happened.
+    Panic {
+        message: String,
+        location: Option<String>,
+    },
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error(message) => write!(f, "{}", message),
+            Self::Timeout => write!(f, "Timed out"),
+            Self::Panic {
+                message,
+                location: Some(location),
+            } => write!(f, "Panicked at {}: {}", location, message),
+            Self::Panic {
+                message,
+                location: None,
+            } => write!(f, "Panicked: {}", message),
+        }
+    }
+}
+
+thread_local! {
+    /// Set while running parts, whose panics are caught.
+    static RUNS_PART: Cell<bool> = const { Cell::new(false) };
+    /// The last panic caught on this thread, recorded by the panic hook.
+    static LAST_PANIC: RefCell<Option<Failure>> = const { RefCell::new(None) };
+}
+
+/// What a panic was given, when it was a message.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "Box<dyn Any>".to_string(),
+    }
+}
+
+/// Records the panics of parts instead of printing them. Only the hook gets to see
+/// where a panic happened; `catch_unwind` just gets the message.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !RUNS_PART.with(Cell::get) {
+                return default_hook(info);
+            }
+            let failure = Failure::Panic {
+                message: panic_message(info.payload()),
+                location: info.location().map(|location| location.to_string()),
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(failure));
+        }));
+    });
+}
+
+/// Runs `f`, catching a panic as a `Failure::Panic` instead of letting it unwind
+/// further. The panic isn't printed.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_panic_hook();
+    let was_running = RUNS_PART.with(|runs_part| runs_part.replace(true));
+    let caught = panic::catch_unwind(AssertUnwindSafe(f));
+    RUNS_PART.with(|runs_part| runs_part.set(was_running));
+    caught.map_err(|_| {
+        LAST_PANIC
+            .with(|last| last.take())
+            .unwrap_or(Failure::Panic {
+                message: "Unknown panic".to_string(),
+                location: None,
+            })
+    })
+}
+
+/// The main thread gets 8 MiB of stack on most platforms, and some days recurse deeply.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Parses and solves on a thread of its own. A part that panics is reported as
+/// failed, and doesn't take the process down. One that returns an error is reported
+/// with the error.
+///
+/// Errors running the part at all are tagged with the day and part they came from.
+pub fn run_part(solution: &'static dyn DynSolution, part: Part, input: String) -> Result<PartRun> {
+    let metadata = solution.metadata();
+    let failed = || format!("Day {} part {} failed", metadata.day, part);
+
+    let (caught, parse_time, solve_time) = thread::Builder::new()
+        .name(format!("day{:02}-part{}", metadata.day, part))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let (mut parse_time, mut solve_time) = (Duration::ZERO, Duration::ZERO);
+            let caught = catch_panic(|| {
+                alloc::measure(|| {
+                    let now = Instant::now();
+                    let parsed = solution.parse(input);
+                    parse_time = now.elapsed();
+                    let now = Instant::now();
+                    let answer = solution.solve(part, parsed?);
+                    solve_time = now.elapsed();
+                    answer
+                })
+            });
+            (caught, parse_time, solve_time)
+        })
+        .with_context(failed)?
+        .join()
+        .map_err(|_| anyhow!("The part stopped without reporting back"))
+        .with_context(failed)?;
+
+    let run = PartRun {
+        parse_time,
+        solve_time,
+        ..PartRun::unsolved(metadata, part)
+    };
+    Ok(match caught {
+        Ok((Ok(answer), allocations)) => PartRun {
+            answer,
+            allocations: alloc::ENABLED.then_some(allocations),
+            ..run
+        },
+        Ok((Err(err), allocations)) => PartRun {
+            allocations: alloc::ENABLED.then_some(allocations),
+            failure: Some(Failure::Error(format!("{:#}", err))),
+            ..run
+        },
+        Err(failure) => PartRun {
+            failure: Some(failure),
+            ..run
+        },
+    })
+}
+
+/// How often `run_part_in_child` checks on the child.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Runs a part in a child process instead, which `command` starts. The child gets
+/// the input on stdin, and has to print the run as a JSON report of one record, the
+/// way `run <day> <part> --input - --format json` does. It is killed once `timeout`
+/// is up, so a part that runs out of time can't go on using memory and CPU while
+/// the next ones run. As it isn't known how far it got, all of its time is counted
+/// as solving.
+///
+/// Errors are tagged with the day and part they came from.
+pub fn run_part_in_child(
+    solution: &'static dyn DynSolution,
+    part: Part,
+    input: String,
+    timeout: Option<Duration>,
+    mut command: Command,
+) -> Result<PartRun> {
+    let metadata = solution.metadata();
+    let failed = || format!("Day {} part {} failed", metadata.day, part);
+
+    let started = Instant::now();
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(failed)?;
+    // Feeding the input and reading the report go on while waiting, so that neither
+    // side blocks on a full pipe
+    let mut stdin = child.stdin.take().unwrap();
+    thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let mut stdout = child.stdout.take().unwrap();
+    let report = thread::spawn(move || {
+        let mut report = String::new();
+        stdout.read_to_string(&mut report).map(|_| report)
+    });
+
+    let status = loop {
+        if let Some(status) = child.try_wait().with_context(failed)? {
+            break status;
+        }
+        if timeout.is_some_and(|timeout| started.elapsed() >= timeout) {
+            child.kill().with_context(failed)?;
+            child.wait().with_context(failed)?;
+            return Ok(PartRun {
+                solve_time: started.elapsed(),
+                failure: Some(Failure::Timeout),
+                ..PartRun::unsolved(metadata, part)
+            });
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let report = report
+        .join()
+        .map_err(|_| anyhow!("Failed to read the report"))
+        .with_context(failed)?
+        .with_context(failed)?;
+    match from_json(&report).ok().as_deref() {
+        Some([record]) => PartRun::from_record(metadata, part, record).with_context(failed),
+        // Crashed, or was killed from outside
+        _ => Ok(PartRun {
+            solve_time: started.elapsed(),
+            failure: Some(Failure::Error(format!(
+                "The part's process stopped without reporting back ({})",
+                status
+            ))),
+            ..PartRun::unsolved(metadata, part)
+        }),
+    }
+}
+
+impl PartRun {
+    /// A run that didn't get anywhere, to fill in.
+    fn unsolved(metadata: Metadata, part: Part) -> Self {
+        Self {
+            day: metadata.day,
+            title: metadata.title,
+            part,
+            implementation: metadata.status(part),
+            answer: Answer::None,
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            allocations: None,
+            failure: None,
+            verdict: None,
+        }
+    }
+
+    /// Reads back a run of the part from its record (see `to_record`). The verdict
+    /// is left out, to be checked again.
+    fn from_record(
+        metadata: Metadata,
+        part: Part,
+        record: &HashMap<String, Value>,
+    ) -> Result<Self> {
+        let field = |name: &str| {
+            record
+                .get(name)
+                .ok_or(anyhow!("The record has no {}", name))
+        };
+        let string = |name: &str| match field(name)? {
+            Value::Str(s) => Ok(Some(s.clone())),
+            Value::Null => Ok(None),
+            value => Err(anyhow!(
+                "Expected {} to be a string, found {:?}",
+                name,
+                value
+            )),
+        };
+        let int = |name: &str| match field(name)? {
+            &Value::Int(n) => Ok(n),
+            value => Err(anyhow!(
+                "Expected {} to be an integer, found {:?}",
+                name,
+                value
+            )),
+        };
+        let nanos =
+            |name: &str| Ok::<_, anyhow::Error>(Duration::from_nanos(int(name)?.try_into()?));
+
+        if int("day")? != metadata.day.into() || int("part")? != part.number().into() {
+            bail!("The record is of another part");
+        }
+        let failure = match string("status")?.as_deref() {
+            Some("error") => Some(Failure::Error(string("error")?.unwrap_or_default())),
+            Some("timeout") => Some(Failure::Timeout),
+            Some("failed") => Some(Failure::Panic {
+                message: string("panic_message")?.unwrap_or_default(),
+                location: string("panic_location")?,
+            }),
+            _ => None,
+        };
+        let allocations = match record.get("allocations") {
+            None | Some(Value::Null) => None,
+            Some(_) => Some(AllocStats {
+                allocations: int("allocations")?.try_into()?,
+                allocated_bytes: int("allocated_bytes")?.try_into()?,
+                peak_bytes: int("peak_bytes")?.try_into()?,
+            }),
+        };
+        Ok(Self {
+            answer: match field("answer")? {
+                Value::Str(s) => Answer::Str(s.clone()),
+                &Value::Int(n) => Answer::Int(n),
+                Value::Null => Answer::None,
+            },
+            parse_time: nanos("parse_ns")?,
+            solve_time: nanos("solve_ns")?,
+            allocations,
+            failure,
+            ..Self::unsolved(metadata, part)
+        })
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.failure {
+            Some(Failure::Error(_)) => return "error",
+            Some(Failure::Timeout) => return "timeout",
+            Some(Failure::Panic { .. }) => return "failed",
+            None => {}
+        }
+        match self.verdict {
+            None => "ok",
+            Some(Verdict::Pass) => "pass",
+            Some(Verdict::Fail { .. }) => "fail",
+            Some(Verdict::Missing) => "missing",
+        }
+    }
+}
+
+impl ToRecord for PartRun {
+    fn to_record(&self) -> Record {
+        let expected = match &self.verdict {
+            Some(Verdict::Fail { expected }) => Value::Str(expected.clone()),
+            _ => Value::Null,
+        };
+        let (panic_message, panic_location) = match &self.failure {
+            Some(Failure::Panic { message, location }) => (
+                Value::Str(message.clone()),
+                location.clone().map_or(Value::Null, Value::Str),
+            ),
+            _ => (Value::Null, Value::Null),
+        };
+        let error = match &self.failure {
+            Some(Failure::Error(message)) => Value::Str(message.clone()),
+            _ => Value::Null,
+        };
+        let mut record = vec![
+            ("day", Value::Int(self.day.into())),
+            ("title", Value::Str(self.title.to_string())),
+            ("part", Value::Int(self.part.number().into())),
+            (
+                "implementation",
+                Value::Str(self.implementation.name().to_string()),
+            ),
+            ("answer", (&self.answer).into()),
+            ("parse_ns", self.parse_time.into()),
+            ("solve_ns", self.solve_time.into()),
+            ("status", Value::Str(self.status().to_string())),
+            ("expected", expected),
+            ("error", error),
+            ("panic_message", panic_message),
+            ("panic_location", panic_location),
+        ];
+        // Parts that failed have no counts, but every record needs the same fields
+        if alloc::ENABLED {
+            let count = |count: fn(&AllocStats) -> u64| {
+                self.allocations
+                    .map_or(Value::Null, |stats| Value::Int(count(&stats).into()))
+            };
+            record.push(("allocations", count(|stats| stats.allocations)));
+            record.push(("allocated_bytes", count(|stats| stats.allocated_bytes)));
+            record.push(("peak_bytes", count(|stats| stats.peak_bytes)));
+        }
+        record
+    }
+}
+
+/// Flags answers that aren't computed, next to the verdict.
+fn format_status(run: &PartRun) -> String {
+    let verdict = format_verdict(run);
+    match run.implementation {
+        Status::Implemented => verdict,
+        Status::Stubbed | Status::Hardcoded => {
+            let flag = run.implementation.name().to_uppercase();
+            [flag, verdict]
+                .into_iter()
+                .filter(|column| !column.is_empty())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    }
+}
+
+fn format_verdict(run: &PartRun) -> String {
+    match &run.failure {
+        Some(Failure::Error(message)) => return format!("ERROR: {}", message),
+        Some(Failure::Timeout) => return "TIMEOUT".to_string(),
+        Some(Failure::Panic { message, location }) => {
+            return match location {
+                Some(location) => format!("FAILED at {}: {}", location, message),
+                None => format!("FAILED: {}", message),
+            };
+        }
+        None => {}
+    }
+    match &run.verdict {
+        None => String::new(),
+        Some(Verdict::Pass) => "PASS".to_string(),
+        Some(Verdict::Missing) => "MISSING".to_string(),
+        Some(Verdict::Fail { expected }) => format!("FAIL (expected {})", expected),
+    }
+}
+
+/// The allocation columns, which are only shown when allocations are counted.
+fn format_allocations(stats: Option<AllocStats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            "{:>11}  {:>10}  {:>10}  ",
+            stats.allocations,
+            format_bytes(stats.allocated_bytes),
+            format_bytes(stats.peak_bytes)
+        ),
+        None => String::new(),
+    }
+}
+
+/// The table of runs, for printing them as they come.
+pub const RUN_TABLE: Table<PartRun> = Table {
+    header: print_table_header,
+    row: print_table_row,
+    footer: print_table_total,
+};
+
+fn row(columns: String) {
+    println!("{}", columns.trim_end());
+}
+
+pub fn print_table(runs: &[PartRun]) {
+    print_table_header();
+    runs.iter().for_each(print_table_row);
+    print_table_total(runs);
+}
+
+fn print_table_header() {
+    let alloc_header = if alloc::ENABLED {
+        format!(
+            "{:>11}  {:>10}  {:>10}  ",
+            "Allocations", "Allocated", "Peak"
+        )
+    } else {
+        String::new()
+    };
+    row(format!(
+        "{:>3}  {:<24}  {:>4}  {:>20}  {:>12}  {:>12}  {}{}",
+        "Day", "Title", "Part", "Answer", "Parse", "Solve", alloc_header, "Status"
+    ));
+}
+
+fn print_table_row(run: &PartRun) {
+    let answer = if run.implementation == Status::Stubbed {
+        "(stubbed)".to_string()
+    } else if run.answer.is_multiline() {
+        "(see below)".to_string()
+    } else {
+        run.answer.to_string()
+    };
+    row(format!(
+        "{:>3}  {:<24}  {:>4}  {:>20}  {:>12}  {:>12}  {}{}",
+        run.day,
+        run.title,
+        run.part,
+        answer,
+        format!("{:?}", run.parse_time),
+        format!("{:?}", run.solve_time),
+        format_allocations(run.allocations),
+        format_status(run),
+    ));
+    if run.answer.is_multiline() && run.implementation != Status::Stubbed {
+        for line in run.answer.to_string().lines() {
+            row(format!("     {}", line));
+        }
+    }
+}
+
+fn print_table_total(runs: &[PartRun]) {
+    let total_parse: Duration = runs.iter().map(|run| run.parse_time).sum();
+    let total_solve: Duration = runs.iter().map(|run| run.solve_time).sum();
+    let total_allocations = runs
+        .iter()
+        .filter_map(|run| run.allocations)
+        .reduce(|total, stats| AllocStats {
+            allocations: total.allocations + stats.allocations,
+            allocated_bytes: total.allocated_bytes + stats.allocated_bytes,
+            peak_bytes: total.peak_bytes.max(stats.peak_bytes),
+        });
+    row(format!(
+        "{:<59}  {:>12}  {:>12}  {}{}",
+        format!(
+            "Total ({} part{}, {:?})",
+            runs.len(),
+            if runs.len() == 1 { "" } else { "s" },
+            total_parse + total_solve
+        ),
+        format!("{:?}", total_parse),
+        format!("{:?}", total_solve),
+        format_allocations(total_allocations),
+        summarize_status(runs),
+    ));
+}
+
+fn summarize_status(runs: &[PartRun]) -> String {
+    let mut summary = Vec::new();
+    let verdicts: Vec<_> = runs.iter().filter_map(|run| run.verdict.as_ref()).collect();
+    if !verdicts.is_empty() {
+        let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+        summary.push(format!(
+            "{} passed, {} failed, {} missing",
+            count(|v| *v == Verdict::Pass),
+            count(|v| matches!(v, Verdict::Fail { .. })),
+            count(|v| *v == Verdict::Missing),
+        ));
+    }
+    let count = |failure: fn(&Failure) -> bool| {
+        runs.iter()
+            .filter(|run| run.failure.as_ref().is_some_and(failure))
+            .count()
+    };
+    let errors = count(|failure| matches!(failure, Failure::Error(_)));
+    if errors > 0 {
+        summary.push(format!("{} errored", errors));
+    }
+    let timeouts = count(|failure| *failure == Failure::Timeout);
+    if timeouts > 0 {
+        summary.push(format!("{} timed out", timeouts));
+    }
+    let panics = count(|failure| matches!(failure, Failure::Panic { .. }));
+    if panics > 0 {
+        summary.push(format!("{} panicked", panics));
+    }
+    for status in [Status::Stubbed, Status::Hardcoded] {
+        let parts = runs
+            .iter()
+            .filter(|run| run.implementation == status)
+            .count();
+        if parts > 0 {
+            summary.push(format!("{} {}", parts, status));
+        }
+    }
+    summary.join(", ")
+}
+
+/// A day of the calendar, with its metadata once there is a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDay {
+    pub day: u8,
+    pub metadata: Option<Metadata>,
+}
+
+pub fn calendar(registry: &Registry) -> Vec<CalendarDay> {
+    DaySelection::all()
+        .0
+        .map(|day| CalendarDay {
+            day,
+            metadata: registry.get(day).map(|solution| solution.metadata()),
+        })
+        .collect()
+}
+
+impl CalendarDay {
+    fn part_status(&self, part: Part) -> &'static str {
+        self.metadata
+            .map_or("missing", |metadata| metadata.status(part).name())
+    }
+}
+
+impl ToRecord for CalendarDay {
+    fn to_record(&self) -> Record {
+        vec![
+            ("day", Value::Int(self.day.into())),
+            (
+                "title",
+                self.metadata.map_or(Value::Null, |metadata| {
+                    Value::Str(metadata.title.to_string())
+                }),
+            ),
+            ("part1", Value::Str(self.part_status(Part::One).to_string())),
+            ("part2", Value::Str(self.part_status(Part::Two).to_string())),
+        ]
+    }
+}
+
+pub fn print_calendar(days: &[CalendarDay]) {
+    row(format!(
+        "{:>3}  {:<24}  {:<11}  {}",
+        "Day", "Title", "Part 1", "Part 2"
+    ));
+    for day in days {
+        row(format!(
+            "{:>3}  {:<24}  {:<11}  {}",
+            day.day,
+            day.metadata.map_or("", |metadata| metadata.title),
+            day.part_status(Part::One),
+            day.part_status(Part::Two),
+        ));
+    }
+    let implemented = days
+        .iter()
+        .flat_map(|day| Part::ALL.map(|part| day.part_status(part)))
+        .filter(|&status| status == Status::Implemented.name())
+        .count();
+    row(format!(
+        "{} of {} parts implemented",
+        implemented,
+        days.len() * 2
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::to_json;
+    use crate::solution::Solution;
+
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        type Input = u64;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sleepy";
+
+        fn parse(input: String) -> Result<u64> {
+            Ok(input.parse()?)
+        }
+        fn part1(millis: u64) -> Result<Answer> {
+            thread::sleep(Duration::from_millis(millis));
+            Ok(Answer::Int(millis.into()))
+        }
+        fn part2(millis: u64) -> Result<Answer> {
+            Self::part1(millis)
+        }
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = ();
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Broken";
+
+        fn parse(_: String) -> Result<()> {
+            Ok(())
+        }
+        fn part1(_: ()) -> Result<Answer> {
+            todo!()
+        }
+        fn part2(_: ()) -> Result<Answer> {
+            Ok(Answer::None)
+        }
+    }
+
+    #[test]
+    fn panic() {
+        let run = run_part(&Broken, Part::One, String::new()).unwrap();
+        assert_eq!(run.status(), "failed");
+        match run.failure {
+            Some(Failure::Panic { message, location }) => {
+                assert_eq!(message, "not yet implemented");
+                assert!(location.unwrap().starts_with("src/runner.rs:"));
+            }
+            failure => panic!("Expected a panic, got {:?}", failure),
+        }
+
+        let run = run_part(&Broken, Part::Two, String::new()).unwrap();
+        assert_eq!(run.failure, None);
+    }
+
+    #[test]
+    fn error() {
+        let run = run_part(&Sleepy, Part::One, "soon".to_string()).unwrap();
+        assert_eq!(run.status(), "error");
+        match run.failure {
+            Some(Failure::Error(message)) => {
+                assert!(message.starts_with("Failed to parse the input: "))
+            }
+            failure => panic!("Expected an error, got {:?}", failure),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn child() {
+        let sh = |script: &str| {
+            let mut command = Command::new("sh");
+            command.args(["-c", script]);
+            command
+        };
+        let timeout = Some(Duration::from_millis(200));
+
+        let report = to_json(&[run_part(&Sleepy, Part::One, "1".to_string()).unwrap()]);
+        let script = format!("cat > /dev/null; echo '{}'", report);
+        let run = run_part_in_child(&Sleepy, Part::One, "1".to_string(), timeout, sh(&script));
+        let run = run.unwrap();
+        assert_eq!((run.answer, run.failure), (Answer::Int(1), None));
+
+        let started = Instant::now();
+        let run = run_part_in_child(
+            &Sleepy,
+            Part::Two,
+            String::new(),
+            timeout,
+            sh("exec sleep 10"),
+        );
+        let run = run.unwrap();
+        assert_eq!(run.failure, Some(Failure::Timeout));
+        assert_eq!(run.status(), "timeout");
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        let run = run_part_in_child(&Sleepy, Part::One, String::new(), None, sh("exit 3"));
+        assert_eq!(run.unwrap().status(), "error");
+    }
+
+    #[test]
+    fn parse_day_selection() {
+        assert_eq!("all".parse::<DaySelection>().unwrap(), DaySelection(1..=25));
+        assert_eq!("7".parse::<DaySelection>().unwrap(), DaySelection(7..=7));
+        assert_eq!(
+            "1..=10".parse::<DaySelection>().unwrap(),
+            DaySelection(1..=10)
+        );
+        assert_eq!(
+            "1..10".parse::<DaySelection>().unwrap(),
+            DaySelection(1..=9)
+        );
+        assert!("5..5".parse::<DaySelection>().is_err());
+        assert!("x".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// The module of a new day, with both parts stubbed. Like the other days, it is
+/// tested against its samples in `examples/` (see `sample_answers`).
+pub fn module_template(day: u8, title: &str) -> String {
+    let template = r#"use crate::answer::Answer;
+use crate::input;
+use crate::solution::{Solution, Status};
+use anyhow::Result;
+
+pub fn parse_input(input: String) -> Result<Vec<String>> {
+    Ok(input::lines(&input).map(|line| line.to_string()).collect())
+}
+
+pub fn part1(_lines: Vec<String>) -> usize {
+    0
+}
+
+pub fn part2(_lines: Vec<String>) -> usize {
+    0
+}
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    type Input = Vec<String>;
+    const DAY: u8 = {N};
+    const TITLE: &'static str = {TITLE};
+    const STATUS: [Status; 2] = [Status::Stubbed; 2];
+
+    fn parse(input: String) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+    fn part1(lines: Vec<String>) -> Result<Answer> {
+        Ok(part1(lines).into())
+    }
+    fn part2(lines: Vec<String>) -> Result<Answer> {
+        Ok(part2(lines).into())
+    }
+}
+"#;
+    template
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+        .replace("{TITLE}", &format!("{:?}", title))
+}
+
+/// The expected answers to a new day's sample, left empty. Stubbed parts aren't
+/// checked, so they only have to be filled in once a part is implemented.
+pub fn sample_answers(day: u8) -> String {
+    format!(
+        "# The answers to the sample in sample.txt\n[day{:02}]\npart1 = \"\"\npart2 = \"\"\n",
+        day
+    )
+}
+
+/// Adds a day to the `register_days!` list of `days/mod.rs`, keeping it in order.
+pub fn register(days_mod: &str, day: u8) -> Result<String> {
+    let module = format!("day{:02}", day);
+    let entry = format!("    {} => Day{:02},", module, day);
+    let lines: Vec<&str> = days_mod.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("register_days!"))
+        .ok_or(anyhow!("There is no register_days! list"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "}")
+            .ok_or(anyhow!("The register_days! list is not closed"))?;
+
+    let mut at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let Some((name, _)) = line.trim().split_once("=>") else {
+            continue;
+        };
+        let name = name.trim();
+        if name == module {
+            bail!("Day {} is already registered", day);
+        }
+        if name > module.as_str() {
+            at = i;
+            break;
+        }
+    }
+    // Attributes belong to the entry below them
+    while at > start + 1 && lines[at - 1].trim().starts_with("#[") {
+        at -= 1;
+    }
+
+    let mut output: Vec<&str> = lines[..at].to_vec();
+    output.push(&entry);
+    output.extend(&lines[at..]);
+    Ok(output.join("\n") + "\n")
+}
+
+/// Writes the module of a new day and an empty input under `days_dir`, an empty sample
+/// under `examples_dir`, and registers the day in `days_dir/mod.rs`. Returns the files
+/// written.
+pub fn new_day(days_dir: &Path, examples_dir: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("There is no day {}", day);
+    }
+    let day_dir = days_dir.join(format!("day{:02}", day));
+    let example_dir = examples_dir.join(format!("day{:02}", day));
+    for dir in [&day_dir, &example_dir] {
+        if dir.exists() {
+            bail!("{:?} already exists", dir);
+        }
+    }
+    let mod_file = days_dir.join("mod.rs");
+    let days_mod = std::fs::read_to_string(&mod_file)
+        .with_context(|| format!("Failed to read {:?}", mod_file))?;
+    let days_mod = register(&days_mod, day)?;
+
+    let files = vec![
+        day_dir.join("mod.rs"),
+        day_dir.join("input.txt"),
+        example_dir.join("sample.txt"),
+        example_dir.join("sample.toml"),
+        mod_file,
+    ];
+    for dir in [&day_dir, &example_dir] {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {:?}", dir))?;
+    }
+    let contents = [
+        module_template(day, title),
+        String::new(),
+        String::new(),
+        sample_answers(day),
+        days_mod,
+    ];
+    for (path, content) in files.iter().zip(contents) {
+        std::fs::write(path, content).with_context(|| format!("Failed to write {:?}", path))?;
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_in_order() {
+        let days_mod = "use crate::register_days;\n\nregister_days! {\n    day01 => Day01,\n    #[ignore]\n    day03 => Day03,\n}\n";
+        assert_eq!(
+            register(days_mod, 2).unwrap(),
+            "use crate::register_days;\n\nregister_days! {\n    day01 => Day01,\n    day02 => Day02,\n    #[ignore]\n    day03 => Day03,\n}\n"
+        );
+        assert!(register(&register(days_mod, 4).unwrap(), 4).is_err());
+        assert!(register(&register(days_mod, 4).unwrap(), 3).is_err());
+        assert!(register(days_mod, 4)
+            .unwrap()
+            .ends_with("    day03 => Day03,\n    day04 => Day04,\n}\n"));
+    }
+
+    #[test]
+    fn template() {
+        let module = module_template(7, "The \"Treachery\" of Whales");
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains(r#"const TITLE: &'static str = "The \"Treachery\" of Whales";"#));
+        assert!(module.ends_with("    }\n}\n"));
+        assert!(sample_answers(7).contains("[day07]\npart1 = \"\"\n"));
+    }
+}
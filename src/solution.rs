@@ -0,0 +1,212 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::rng::Rng;
+use crate::visualize::Frames;
+use anyhow::{Context, Result};
+use std::any::Any;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// How a part gets to its answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Computed from the input.
+    Implemented,
+    /// A placeholder answer, for a part that is missing or a work in progress.
+    Stubbed,
+    /// The answer to one particular input, worked out some other way.
+    Hardcoded,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Implemented => "implemented",
+            Self::Stubbed => "stubbed",
+            Self::Hardcoded => "hardcoded",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    pub day: u8,
+    pub title: &'static str,
+    /// The status of each part.
+    pub status: [Status; 2],
+}
+
+impl Metadata {
+    pub fn status(&self, part: Part) -> Status {
+        self.status[part.number() as usize - 1]
+    }
+}
+
+/// A single day of the calendar. `parse` turns the raw puzzle input into whatever
+/// the parts work on, so parsing can be timed (and reused) separately from solving.
+/// Malformed input is reported as an error rather than a panic. The input is
+/// normalized first (see `input::normalize`).
+pub trait Solution {
+    type Input;
+
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// The status of each part, when they aren't both implemented.
+    const STATUS: [Status; 2] = [Status::Implemented; 2];
+
+    fn parse(input: String) -> Result<Self::Input>;
+    fn part1(input: Self::Input) -> Result<Answer>;
+    fn part2(input: Self::Input) -> Result<Answer>;
+
+    /// Days that simulate something can replay a part as text frames.
+    fn visualize(_input: Self::Input, _part: Part) -> Result<Option<Frames>> {
+        Ok(None)
+    }
+
+    /// Writes a random puzzle input. What `size` means is up to the day, such as
+    /// a number of lines or the side of a grid; each day has its own default.
+    fn generate(_rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+}
+
+/// Type-erased view of a `Solution`, so days with different inputs can share a registry.
+pub trait DynSolution: Sync {
+    fn metadata(&self) -> Metadata;
+    fn parse(&self, input: String) -> Result<Box<dyn Any>>;
+    fn solve(&self, part: Part, input: Box<dyn Any>) -> Result<Answer>;
+    fn visualize(&self, part: Part, input: Box<dyn Any>) -> Result<Option<Frames>>;
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String>;
+}
+
+fn downcast<S: Solution>(input: Box<dyn Any>) -> S::Input
+where
+    S::Input: 'static,
+{
+    *input
+        .downcast::<S::Input>()
+        .expect("Solved with an input parsed by another day")
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            day: S::DAY,
+            title: S::TITLE,
+            status: S::STATUS,
+        }
+    }
+
+    fn parse(&self, input: String) -> Result<Box<dyn Any>> {
+        let input = S::parse(input::normalize(&input)).context("Failed to parse the input")?;
+        Ok(Box::new(input))
+    }
+
+    fn solve(&self, part: Part, input: Box<dyn Any>) -> Result<Answer> {
+        let input = downcast::<S>(input);
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+
+    fn visualize(&self, part: Part, input: Box<dyn Any>) -> Result<Option<Frames>> {
+        S::visualize(downcast::<S>(input), part)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        S::generate(rng, size)
+    }
+}
+
+pub struct Registry {
+    solutions: Vec<&'static dyn DynSolution>,
+}
+
+impl Registry {
+    pub fn new(mut solutions: Vec<&'static dyn DynSolution>) -> Self {
+        solutions.sort_by_key(|s| s.metadata().day);
+        Self { solutions }
+    }
+
+    pub fn get(&self, day: u8) -> Option<&'static dyn DynSolution> {
+        self.solutions
+            .iter()
+            .find(|s| s.metadata().day == day)
+            .copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn DynSolution> + '_ {
+        self.solutions.iter().copied()
+    }
+}
+
+/// Declares the day modules and builds a `Registry` with each module's solution.
+/// Every `dayNN` module exposes a unit struct implementing `Solution`.
+///
+/// Each day also gets a test, `example_tests::dayNN`, checking it against its
+/// samples in `examples/` (see `examples::check`). Attributes on an entry are put
+/// on its test, e.g. `#[ignore]` for a day too slow to run in a debug build.
+#[macro_export]
+macro_rules! register_days {
+    ($($(#[$attr:meta])* $module:ident => $solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub fn registry() -> $crate::solution::Registry {
+            $crate::solution::Registry::new(vec![$(&$module::$solution),*])
+        }
+
+        #[cfg(test)]
+        mod example_tests {
+            $(
+                $(#[$attr])*
+                #[test]
+                fn $module() {
+                    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+                    let failures = $crate::examples::check(&super::$module::$solution, &dir).unwrap();
+                    assert!(failures.is_empty(), "{}", failures.join("\n"));
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,106 @@
+use crate::solution::{DynSolution, Part};
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The states a part goes through, rendered as text. Frames are produced lazily,
+/// so playback can start before a long simulation ends.
+pub type Frames = Box<dyn Iterator<Item = String>>;
+
+/// Frames of a simulation: the initial state, then the state after each `step`,
+/// until `step` returns false to say there was nothing left to do.
+pub fn simulate<S: 'static>(
+    state: S,
+    mut step: impl FnMut(&mut S) -> bool + 'static,
+    render: impl Fn(&S) -> String + 'static,
+) -> Frames {
+    let mut state = Some(state);
+    let mut started = false;
+    Box::new(std::iter::from_fn(move || {
+        let current = state.as_mut()?;
+        if started && !step(current) {
+            state = None;
+            return None;
+        }
+        started = true;
+        Some(render(current))
+    }))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Playback {
+    /// Redraw the terminal for every frame.
+    Terminal { fps: u32 },
+    /// Write every frame to `<dir>/dayNN-partN/NNNNN.txt`.
+    Directory(PathBuf),
+}
+
+impl Playback {
+    /// Plays the frames back, returning how many there were.
+    pub fn play(&self, frames: Frames, day: u8, part: Part) -> Result<usize> {
+        let mut count = 0;
+        match self {
+            Self::Terminal { fps } => {
+                let delay = Duration::from_secs(1) / (*fps).max(1);
+                let mut stdout = std::io::stdout();
+                for frame in frames {
+                    count += 1;
+                    // Clear the screen and move the cursor to the top left
+                    write!(stdout, "\x1b[2J\x1b[H")?;
+                    writeln!(stdout, "Day {} part {}, frame {}", day, part, count)?;
+                    writeln!(stdout, "{}", frame)?;
+                    stdout.flush()?;
+                    std::thread::sleep(delay);
+                }
+            }
+            Self::Directory(dir) => {
+                let dir = dir.join(format!("day{:02}-part{}", day, part));
+                std::fs::create_dir_all(&dir)
+                    .with_context(|| format!("Failed to create {:?}", dir))?;
+                for frame in frames {
+                    let path = dir.join(format!("{:05}.txt", count));
+                    std::fs::write(&path, frame)
+                        .with_context(|| format!("Failed to write {:?}", path))?;
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Parses the input and plays the part back. Returns `None` when the day has no
+/// visualization, and the number of frames otherwise.
+pub fn visualize_part(
+    solution: &dyn DynSolution,
+    part: Part,
+    input: String,
+    playback: &Playback,
+) -> Result<Option<usize>> {
+    let day = solution.metadata().day;
+    let failed = || format!("Day {} part {} failed", day, part);
+    let parsed = solution.parse(input).with_context(failed)?;
+    match solution.visualize(part, parsed).with_context(failed)? {
+        Some(frames) => Ok(Some(playback.play(frames, day, part)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulation_frames() {
+        let frames = simulate(
+            0,
+            |n| {
+                *n += 1;
+                *n <= 3
+            },
+            |n| n.to_string(),
+        );
+        assert_eq!(frames.collect::<Vec<_>>(), ["0", "1", "2", "3"]);
+    }
+}
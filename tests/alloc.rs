@@ -0,0 +1,25 @@
+// A test binary of its own, so no other test allocates while this one counts
+#![cfg(feature = "count-allocations")]
+
+use aoc2021::alloc::{measure, AllocStats, CountingAllocator};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn measure_counts_and_peak() {
+    let (kept, stats) = measure(|| {
+        drop(black_box(Vec::<u8>::with_capacity(500)));
+        black_box(Vec::<u8>::with_capacity(1000))
+    });
+    assert_eq!(
+        stats,
+        AllocStats {
+            allocations: 2,
+            allocated_bytes: 1500,
+            peak_bytes: 1000,
+        }
+    );
+    drop(kept);
+}